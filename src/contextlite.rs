@@ -6,17 +6,148 @@
 use crate::error::DatabaseError;
 use crate::types::{Species, CultivationRecord};
 use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
 use uuid::Uuid;
 
 #[cfg(feature = "contextlite")]
 use contextlite_client::ContextLiteClient;
 
+/// Size (in characters) of each indexed text chunk.
+const CHUNK_WINDOW_CHARS: usize = 512;
+
+/// Overlap (in characters) between consecutive chunks, so a fact split
+/// across a chunk boundary is still fully present in at least one chunk.
+const CHUNK_OVERLAP_CHARS: usize = 64;
+
+/// Produces a fixed-size embedding vector for a chunk of text.
+///
+/// Implementations are expected to be deterministic for a given text and
+/// to always return vectors of [`Embedder::dimensions`] length, so stored
+/// and queried embeddings stay comparable.
+pub trait Embedder: std::fmt::Debug + Send + Sync {
+    /// Embed a chunk of text into a dense vector.
+    fn embed(&self, text: &str) -> Vec<f32>;
+
+    /// The dimensionality of vectors this embedder produces.
+    fn dimensions(&self) -> usize;
+}
+
+/// Offline fallback embedder that hashes character shingles into a fixed
+/// number of buckets. It has no external dependencies, so `index_plant_data`
+/// and the query path work fully offline unless a real model is plugged in.
+#[derive(Debug, Clone)]
+pub struct HashingEmbedder {
+    dims: usize,
+}
+
+impl HashingEmbedder {
+    /// Create a hashing embedder that produces `dims`-dimensional vectors.
+    pub fn new(dims: usize) -> Self {
+        Self { dims }
+    }
+}
+
+impl Default for HashingEmbedder {
+    fn default() -> Self {
+        Self::new(128)
+    }
+}
+
+impl Embedder for HashingEmbedder {
+    fn embed(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0f32; self.dims];
+        let normalized = text.to_lowercase();
+        let bytes = normalized.as_bytes();
+
+        for window in bytes.windows(3.min(bytes.len().max(1))) {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            std::hash::Hash::hash(window, &mut hasher);
+            let bucket = (std::hash::Hasher::finish(&hasher) as usize) % self.dims;
+            vector[bucket] += 1.0;
+        }
+
+        normalize_l2(&mut vector);
+        vector
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dims
+    }
+}
+
+/// Normalizes a vector to unit length in place (no-op on an all-zero vector).
+fn normalize_l2(vector: &mut [f32]) {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for v in vector.iter_mut() {
+            *v /= norm;
+        }
+    }
+}
+
+/// Serializes an embedding as little-endian `f32` bytes for BLOB storage.
+fn encode_embedding(vector: &[f32]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(vector.len() * 4);
+    for value in vector {
+        bytes.extend_from_slice(&value.to_le_bytes());
+    }
+    bytes
+}
+
+/// Deserializes little-endian `f32` bytes back into an embedding vector.
+fn decode_embedding(bytes: &[u8]) -> Result<Vec<f32>, DatabaseError> {
+    if bytes.len() % 4 != 0 {
+        return Err(DatabaseError::validation(
+            "stored embedding is not a whole number of f32 values",
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect())
+}
+
+/// Splits `text` into overlapping character windows so long documents are
+/// indexed as several retrievable chunks instead of one oversized blob.
+fn chunk_text(text: &str, window: usize, overlap: usize) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.is_empty() {
+        return Vec::new();
+    }
+
+    let step = window.saturating_sub(overlap).max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    while start < chars.len() {
+        let end = (start + window).min(chars.len());
+        chunks.push(chars[start..end].iter().collect());
+        if end == chars.len() {
+            break;
+        }
+        start += step;
+    }
+
+    chunks
+}
+
+/// A candidate chunk retrieved from `botanical_vectors`, scored against a query.
+#[derive(Debug, Clone)]
+struct ScoredChunk {
+    source: String,
+    text: String,
+    score: f32,
+}
+
 /// ContextLite integration for botanical knowledge
 #[derive(Debug, Clone)]
 pub struct BotanicalContext {
     #[cfg(feature = "contextlite")]
     client: ContextLiteClient,
     workspace_id: String,
+    pool: SqlitePool,
+    embedder: std::sync::Arc<dyn Embedder>,
 }
 
 /// Plant context query parameters
@@ -52,124 +183,195 @@ pub struct ContextDocument {
 }
 
 impl BotanicalContext {
-    /// Create new botanical context client
+    /// Create a new botanical context backed by `pool`, using the offline
+    /// [`HashingEmbedder`] unless overridden with [`BotanicalContext::with_embedder`].
     #[cfg(feature = "contextlite")]
-    pub fn new(base_url: &str, _auth_token: &str, workspace_id: &str) -> Result<Self, DatabaseError> {
+    pub fn new(pool: SqlitePool, base_url: &str, _auth_token: &str, workspace_id: &str) -> Result<Self, DatabaseError> {
         let client = ContextLiteClient::new(base_url)
             .map_err(|e| DatabaseError::ContextLiteError(e.to_string()))?;
-        
+
         Ok(Self {
             client,
             workspace_id: workspace_id.to_string(),
+            pool,
+            embedder: std::sync::Arc::new(HashingEmbedder::default()),
         })
     }
 
-    /// Create new botanical context client (no-op without contextlite feature)
+    /// Create a new botanical context backed by `pool` (no-op ContextLite client
+    /// without the `contextlite` feature; the vector search pipeline still works).
     #[cfg(not(feature = "contextlite"))]
-    pub fn new(_base_url: &str, _auth_token: &str, workspace_id: &str) -> Result<Self, DatabaseError> {
+    pub fn new(pool: SqlitePool, _base_url: &str, _auth_token: &str, workspace_id: &str) -> Result<Self, DatabaseError> {
         Ok(Self {
             workspace_id: workspace_id.to_string(),
+            pool,
+            embedder: std::sync::Arc::new(HashingEmbedder::default()),
         })
     }
 
-    /// Get AI-powered plant care recommendations
-    #[cfg(feature = "contextlite")]
-    pub async fn get_plant_recommendations(
+    /// Swap in a custom [`Embedder`] (e.g. a local model or a remote endpoint).
+    pub fn with_embedder(mut self, embedder: impl Embedder + 'static) -> Self {
+        self.embedder = std::sync::Arc::new(embedder);
+        self
+    }
+
+    /// Split `species`/cultivation data into overlapping chunks, embed each one,
+    /// and upsert them into `botanical_vectors` for later retrieval.
+    pub async fn index_plant_data(
         &self,
         species: &Species,
-        cultivation_records: &[CultivationRecord],
+        records: &[CultivationRecord],
+    ) -> Result<(), DatabaseError> {
+        let document_id = species.id.to_string();
+
+        let mut blob = format!(
+            "Species: {} ({})\nGenus: {}\n",
+            species.specific_epithet, species.authority, species.genus_id
+        );
+        for record in records {
+            blob.push_str(&format!("Stage: {:?}\n", record.growth_stage));
+            if let Some(notes) = &record.notes {
+                blob.push_str(&format!("Notes: {}\n", notes));
+            }
+        }
+
+        sqlx::query("DELETE FROM botanical_vectors WHERE document_id = ?")
+            .bind(&document_id)
+            .execute(&self.pool)
+            .await?;
+
+        for (chunk_index, chunk) in chunk_text(&blob, CHUNK_WINDOW_CHARS, CHUNK_OVERLAP_CHARS)
+            .into_iter()
+            .enumerate()
+        {
+            let mut embedding = self.embedder.embed(&chunk);
+            normalize_l2(&mut embedding);
+
+            sqlx::query(
+                "INSERT INTO botanical_vectors (document_id, chunk_index, source, text, embedding) VALUES (?, ?, ?, ?, ?)"
+            )
+            .bind(&document_id)
+            .bind(chunk_index as i64)
+            .bind("species")
+            .bind(&chunk)
+            .bind(encode_embedding(&embedding))
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Rank indexed chunks against `query`, optionally filtered to `plant_id`,
+    /// and return the top `max_documents` scored by cosine similarity.
+    async fn retrieve(
+        &self,
         query: &str,
-    ) -> Result<PlantContextResponse, DatabaseError> {
-        // Build context from plant data
-        let mut context_parts = vec![
-            format!("Species: {}", species.specific_epithet),
-            format!("Authority: {}", species.authority),
-            format!("Genus: {}", species.genus_id),
-        ];
+        plant_id: Option<Uuid>,
+        max_documents: usize,
+    ) -> Result<Vec<ScoredChunk>, DatabaseError> {
+        let mut query_vector = self.embedder.embed(query);
+        normalize_l2(&mut query_vector);
+
+        let rows = if let Some(plant_id) = plant_id {
+            sqlx::query("SELECT source, text, embedding FROM botanical_vectors WHERE document_id = ?")
+                .bind(plant_id.to_string())
+                .fetch_all(&self.pool)
+                .await?
+        } else {
+            sqlx::query("SELECT source, text, embedding FROM botanical_vectors")
+                .fetch_all(&self.pool)
+                .await?
+        };
 
-        if !cultivation_records.is_empty() {
-            let latest_record = &cultivation_records[cultivation_records.len() - 1];
-            context_parts.push(format!("Current stage: {:?}", latest_record.growth_stage));
-            if let Some(notes) = &latest_record.notes {
-                context_parts.push(format!("Notes: {}", notes));
+        let mut scored = Vec::with_capacity(rows.len());
+        for row in rows {
+            let source: String = row.get("source");
+            let text: String = row.get("text");
+            let embedding_bytes: Vec<u8> = row.get("embedding");
+            let candidate = decode_embedding(&embedding_bytes)?;
+
+            if candidate.len() != query_vector.len() {
+                return Err(DatabaseError::validation(format!(
+                    "embedding dimensionality mismatch: query has {} dims, stored chunk has {}",
+                    query_vector.len(),
+                    candidate.len()
+                )));
             }
+
+            let score: f32 = query_vector.iter().zip(candidate.iter()).map(|(a, b)| a * b).sum();
+            scored.push(ScoredChunk { source, text, score });
         }
 
-        // TODO: Implement actual ContextLite API call once we discover correct method
-        // For now, provide mock response
-        Ok(PlantContextResponse {
-            plant_id: species.id, // Using species ID as plant ID for now
-            query: query.to_string(),
-            context: format!("Context for {} ({})", species.specific_epithet, query),
-            recommendations: vec!["Mock recommendation".to_string()],
-            relevant_documents: vec![],
-            confidence_score: 0.8,
-        })
+        scored.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(max_documents);
+        Ok(scored)
     }
 
-    /// Get AI-powered plant care recommendations (mock without contextlite feature)
-    #[cfg(not(feature = "contextlite"))]
+    /// Get AI-powered plant care recommendations grounded in indexed cultivation
+    /// notes and species data via the vector search pipeline.
     pub async fn get_plant_recommendations(
         &self,
         species: &Species,
         _cultivation_records: &[CultivationRecord],
-        query: &str,
+        params: &PlantContextQuery,
     ) -> Result<PlantContextResponse, DatabaseError> {
-        // Mock response when ContextLite is not available
+        let hits = self
+            .retrieve(&params.query, Some(params.plant_id), params.max_documents)
+            .await?;
+
+        let context = assemble_within_budget(&hits, params.max_tokens);
+        let confidence_score = if hits.is_empty() {
+            0.0
+        } else {
+            hits.iter().map(|h| h.score).sum::<f32>() / hits.len() as f32
+        };
+
         Ok(PlantContextResponse {
-            plant_id: species.id, // Using species ID as plant ID for now
-            query: query.to_string(),
-            context: "ContextLite feature not enabled".to_string(),
-            recommendations: vec!["Enable ContextLite feature for AI recommendations".to_string()],
-            relevant_documents: vec![],
-            confidence_score: 0.0,
+            plant_id: species.id,
+            query: params.query.clone(),
+            context,
+            recommendations: extract_recommendations(&hits.iter().map(|h| h.text.as_str()).collect::<Vec<_>>().join("\n")),
+            relevant_documents: hits
+                .into_iter()
+                .enumerate()
+                .map(|(i, hit)| ContextDocument {
+                    id: format!("{}-{}", species.id, i),
+                    title: format!("{} chunk {}", hit.source, i),
+                    source: hit.source,
+                    relevance_score: hit.score,
+                    content_snippet: hit.text,
+                })
+                .collect(),
+            confidence_score,
         })
     }
 
-    /// Query general botanical knowledge
-    #[cfg(feature = "contextlite")]
-    pub async fn query_botanical_knowledge(&self, query: &str) -> Result<String, DatabaseError> {
-        // TODO: Implement actual ContextLite API call
-        Ok(format!("Mock botanical knowledge for: {}", query))
+    /// Query general botanical knowledge across all indexed documents.
+    pub async fn query_botanical_knowledge(&self, params: &PlantContextQuery) -> Result<String, DatabaseError> {
+        let hits = self.retrieve(&params.query, None, params.max_documents).await?;
+        Ok(assemble_within_budget(&hits, params.max_tokens))
     }
+}
 
-    /// Query general botanical knowledge (mock without contextlite feature)
-    #[cfg(not(feature = "contextlite"))]
-    pub async fn query_botanical_knowledge(&self, query: &str) -> Result<String, DatabaseError> {
-        Ok(format!("ContextLite feature not enabled for query: {}", query))
-    }
-
-    /// Add plant data to ContextLite knowledge base
-    #[cfg(feature = "contextlite")]
-    pub async fn index_plant_data(
-        &self,
-        species: &Species,
-        records: &[CultivationRecord],
-    ) -> Result<(), DatabaseError> {
-        // Assemble plant data into ContextLite document
-        let _plant_data = format!(
-            "SPECIES: {} ({})\nRECORDS: {}",
-            species.specific_epithet,
-            species.authority,
-            records.len()
-        );
-
-        // TODO: Implement actual ContextLite document indexing
-        // For now, just log the data being indexed
-        log::info!("Would index {} records for species {}", records.len(), species.specific_epithet);
+/// Concatenates scored chunks until the running character budget (a rough
+/// stand-in for a token budget) would be exceeded.
+fn assemble_within_budget(hits: &[ScoredChunk], max_tokens: usize) -> String {
+    let mut assembled = String::new();
+    let mut used = 0;
 
-        Ok(())
+    for hit in hits {
+        if used + hit.text.len() > max_tokens {
+            break;
+        }
+        if !assembled.is_empty() {
+            assembled.push_str("\n---\n");
+        }
+        assembled.push_str(&hit.text);
+        used += hit.text.len();
     }
 
-    /// Add plant data to ContextLite knowledge base (no-op without contextlite feature)
-    #[cfg(not(feature = "contextlite"))]
-    pub async fn index_plant_data(
-        &self,
-        _species: &Species,
-        _records: &[CultivationRecord],
-    ) -> Result<(), DatabaseError> {
-        Ok(()) // No-op when ContextLite is not available
-    }
+    assembled
 }
 
 /// Extract recommendations from context text
@@ -210,9 +412,17 @@ mod tests {
     use super::*;
     use crate::types::GrowthStage;
 
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePool::connect("sqlite::memory:").await.expect("Failed to open pool");
+        crate::migrations::run_migrations(&pool).await.expect("Failed to run migrations");
+        pool
+    }
+
     #[tokio::test]
     async fn test_botanical_context_creation() {
+        let pool = test_pool().await;
         let context = BotanicalContext::new(
+            pool,
             "http://localhost:8090",
             "test-token",
             "budsy-cultivation"
@@ -222,9 +432,11 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_mock_recommendations() {
+    async fn test_indexed_recommendations_are_grounded_in_chunks() {
+        let pool = test_pool().await;
         let context = BotanicalContext::new(
-            "http://localhost:8090", 
+            pool,
+            "http://localhost:8090",
             "test-token",
             "test-workspace"
         ).expect("Failed to create context");
@@ -245,22 +457,46 @@ mod tests {
             )
         ];
 
-        let response = context.get_plant_recommendations(
-            &species,
-            &records,
-            "How is my plant doing?"
-        ).await.expect("Failed to get recommendations");
+        context.index_plant_data(&species, &records).await.expect("Failed to index plant data");
+
+        let query = PlantContextQuery {
+            plant_id: species.id,
+            query: "How is my plant doing?".to_string(),
+            include_cultivation_history: true,
+            include_species_data: true,
+            max_documents: 5,
+            max_tokens: 4096,
+        };
+
+        let response = context.get_plant_recommendations(&species, &records, &query)
+            .await
+            .expect("Failed to get recommendations");
 
         assert_eq!(response.plant_id, species.id);
+        assert!(!response.relevant_documents.is_empty(), "Should retrieve at least one indexed chunk");
         assert!(!response.recommendations.is_empty());
     }
 
+    #[test]
+    fn test_embedding_roundtrip_preserves_values() {
+        let vector = vec![0.25f32, -0.5, 1.0, 0.0];
+        let encoded = encode_embedding(&vector);
+        let decoded = decode_embedding(&encoded).expect("Failed to decode embedding");
+        assert_eq!(vector, decoded);
+    }
+
+    #[test]
+    fn test_chunk_text_overlaps_windows() {
+        let text = "a".repeat(1000);
+        let chunks = chunk_text(&text, 512, 64);
+        assert!(chunks.len() >= 2, "Long text should split into multiple chunks");
+        assert_eq!(chunks[0].len(), 512);
+    }
+
     #[test]
     fn test_recommendation_extraction() {
-        // TODO: Test recommendation extraction once ContextLite API is working
-        // For now, test basic text pattern matching
         let test_context = "The plant shows signs of nutrient deficiency and may need water adjustment";
-        
+
         assert!(test_context.contains("nutrient"));
         assert!(test_context.contains("water"));
     }
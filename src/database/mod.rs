@@ -1,15 +1,36 @@
-use sqlx::SqlitePool;
+use sqlx::{Sqlite, SqlitePool, Transaction};
 use crate::error::DatabaseError;
+use crate::queries::{family, genus, species};
+use crate::types::{Family, Genus, Species};
+
+#[cfg(feature = "postgres")]
+use sqlx::PgPool;
+
+/// Which database engine a [`DatabaseConfig`] connects to.
+///
+/// `BotanicalDatabase` only opens `Sqlite`, and (behind the `postgres`
+/// feature) `PgBotanicalDatabase` only opens `Postgres` - see [`connect`]
+/// for picking between them from one `DatabaseConfig` at runtime. `MySql`
+/// remains scaffolding ahead of a real connector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    Sqlite,
+    Postgres,
+    MySql,
+}
 
 /// Configuration for the botanical database connection
 #[derive(Debug, Clone)]
 pub struct DatabaseConfig {
-    /// Database connection URL (SQLite file path or :memory:)
+    /// Database connection URL (SQLite file path, `:memory:`, or a `postgres://` URL)
     pub url: String,
-    
+
+    /// Which engine `url` points at
+    pub backend: DatabaseBackend,
+
     /// Maximum number of connections in the pool
     pub max_connections: u32,
-    
+
     /// Enable foreign key constraints
     pub foreign_keys: bool,
 }
@@ -18,6 +39,7 @@ impl Default for DatabaseConfig {
     fn default() -> Self {
         Self {
             url: "sqlite:botanical.db".to_string(),
+            backend: DatabaseBackend::Sqlite,
             max_connections: 10,
             foreign_keys: true,
         }
@@ -29,19 +51,73 @@ impl DatabaseConfig {
     pub fn memory() -> Self {
         Self {
             url: "sqlite::memory:".to_string(),
+            backend: DatabaseBackend::Sqlite,
             max_connections: 1,
             foreign_keys: true,
         }
     }
-    
+
     /// Create a new database configuration for file-based database
     pub fn file<S: AsRef<str>>(path: S) -> Self {
         Self {
             url: format!("sqlite:{}", path.as_ref()),
+            backend: DatabaseBackend::Sqlite,
             max_connections: 10,
             foreign_keys: true,
         }
     }
+
+    /// Create a new database configuration targeting a PostgreSQL server.
+    ///
+    /// Pass this to [`connect`] (under the `postgres` feature) to open a
+    /// [`PgBotanicalDatabase`] instead of a SQLite [`BotanicalDatabase`].
+    /// `BotanicalDatabase::new` itself still rejects this backend: only
+    /// `queries::species_pg` and `queries::search_pg` have Postgres
+    /// counterparts so far, not the rest of `queries::*`.
+    pub fn postgres<S: Into<String>>(url: S) -> Self {
+        Self {
+            url: url.into(),
+            backend: DatabaseBackend::Postgres,
+            max_connections: 10,
+            foreign_keys: false,
+        }
+    }
+
+    /// Create a new database configuration targeting a MySQL server.
+    ///
+    /// Same caveat as [`DatabaseConfig::postgres`]: `BotanicalDatabase::new`
+    /// rejects this backend until a MySQL connector is implemented.
+    pub fn mysql<S: Into<String>>(url: S) -> Self {
+        Self {
+            url: url.into(),
+            backend: DatabaseBackend::MySql,
+            max_connections: 10,
+            foreign_keys: false,
+        }
+    }
+}
+
+/// Common surface every backend-specific database handle exposes.
+///
+/// `BotanicalDatabase` implements this over its SQLite pool, and (behind the
+/// `postgres` feature) `PgBotanicalDatabase` implements it over a Postgres
+/// pool - a new backend means implementing the trait for a new type rather
+/// than branching on [`DatabaseBackend`] inside `BotanicalDatabase` itself.
+/// Most of `queries::*` is still not generic over this trait (it takes
+/// `&SqlitePool` directly); only `species`/`species_pg` and
+/// `search`/`search_pg` (species search) have grown Postgres counterparts
+/// so far, so this remains the outer boundary of the
+/// abstraction rather than a full rewrite of the query layer.
+#[async_trait::async_trait]
+pub trait Database: Send + Sync {
+    /// Which engine this handle is backed by.
+    fn backend(&self) -> DatabaseBackend;
+
+    /// Run this backend's migrations, creating/updating schema as needed.
+    async fn migrate(&self) -> Result<(), DatabaseError>;
+
+    /// Verify the connection is alive and able to serve queries.
+    async fn health_check(&self) -> Result<(), DatabaseError>;
 }
 
 /// Main database connection pool for botanical operations
@@ -52,18 +128,32 @@ pub struct BotanicalDatabase {
 }
 
 impl BotanicalDatabase {
-    /// Create a new database connection from configuration
+    /// Create a new database connection from configuration.
+    ///
+    /// The schema migrator runs to completion here (a static migrator
+    /// applied at pool construction), so callers get a fully migrated
+    /// database back instead of having to remember a separate `.migrate()`
+    /// step before issuing queries.
     pub async fn new(config: DatabaseConfig) -> Result<Self, DatabaseError> {
+        if config.backend != DatabaseBackend::Sqlite {
+            return Err(DatabaseError::config(format!(
+                "backend {:?} is not yet supported by BotanicalDatabase; only Sqlite connects today",
+                config.backend,
+            )));
+        }
+
         let pool = SqlitePool::connect(&config.url).await?;
-        
+
         // Enable foreign key constraints if requested
         if config.foreign_keys {
             sqlx::query("PRAGMA foreign_keys = ON")
                 .execute(&pool)
                 .await?;
         }
-        
-        Ok(Self { pool })
+
+        let database = Self { pool };
+        database.migrate().await?;
+        Ok(database)
     }
     
     /// Create a new in-memory database for testing
@@ -75,7 +165,24 @@ impl BotanicalDatabase {
     pub async fn migrate(&self) -> Result<(), DatabaseError> {
         crate::migrations::run_migrations(&self.pool).await
     }
-    
+
+    /// Steps the schema to exactly `version`, applying pending migrations
+    /// forward or rolling back already-applied ones via their `down`
+    /// scripts, whichever direction `version` requires.
+    pub async fn migrate_to(&self, version: i64) -> Result<(), DatabaseError> {
+        crate::migrations::migrate_to(&self.pool, version).await
+    }
+
+    /// Rolls the schema back by `steps` applied migrations.
+    pub async fn rollback(&self, steps: i64) -> Result<(), DatabaseError> {
+        crate::migrations::rollback(&self.pool, steps).await
+    }
+
+    /// The highest migration version currently applied, or `0` if none have run.
+    pub async fn schema_version(&self) -> Result<i64, DatabaseError> {
+        crate::migrations::schema_version(&self.pool).await
+    }
+
     /// Check if the database connection is healthy
     pub async fn health_check(&self) -> Result<(), DatabaseError> {
         sqlx::query("SELECT 1")
@@ -88,9 +195,147 @@ impl BotanicalDatabase {
     pub fn pool(&self) -> &SqlitePool {
         &self.pool
     }
+
+    /// Begin a new transaction against this database's pool.
+    ///
+    /// Callers can run any `queries::*_tx` function against the returned
+    /// transaction (or further nest savepoints via `transaction.begin()`) and
+    /// commit or roll back explicitly once all steps have succeeded.
+    pub async fn transaction(&self) -> Result<Transaction<'_, Sqlite>, DatabaseError> {
+        Ok(self.pool.begin().await?)
+    }
+
+    /// Inserts a Family/Genus/Species chain atomically: all three rows are
+    /// inserted in one transaction, which rolls back entirely (e.g. on a
+    /// foreign-key violation) if any insert fails.
+    pub async fn insert_hierarchy(
+        &self,
+        family_row: &Family,
+        genus_row: &Genus,
+        species_row: &Species,
+    ) -> Result<(), DatabaseError> {
+        let mut tx = self.transaction().await?;
+
+        family::insert_family_tx(&mut tx, family_row).await?;
+        genus::insert_genus_tx(&mut tx, genus_row).await?;
+        species::insert_species_tx(&mut tx, species_row).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
     
     /// Close the database connection pool
     pub async fn close(&self) {
         self.pool.close().await;
     }
+}
+
+#[async_trait::async_trait]
+impl Database for BotanicalDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::Sqlite
+    }
+
+    async fn migrate(&self) -> Result<(), DatabaseError> {
+        BotanicalDatabase::migrate(self).await
+    }
+
+    async fn health_check(&self) -> Result<(), DatabaseError> {
+        BotanicalDatabase::health_check(self).await
+    }
+}
+
+/// Postgres counterpart of [`BotanicalDatabase`].
+///
+/// Only wraps what [`crate::queries::species_pg`] and
+/// [`crate::queries::search_pg`] need today - the rest of
+/// `queries::*` is still SQLite-only, so there is no `insert_hierarchy` or
+/// `transaction()` here yet. Widen this alongside `queries::*` growing more
+/// Postgres-backed modules rather than trying to land the whole surface at
+/// once.
+#[cfg(feature = "postgres")]
+#[derive(Debug, Clone)]
+pub struct PgBotanicalDatabase {
+    /// Postgres connection pool
+    pub pool: PgPool,
+}
+
+#[cfg(feature = "postgres")]
+impl PgBotanicalDatabase {
+    /// Create a new Postgres connection from configuration.
+    ///
+    /// Like [`BotanicalDatabase::new`], migrations run to completion here so
+    /// callers get a schema ready for [`crate::queries::species_pg`] without
+    /// a separate `.migrate()` step.
+    pub async fn new(config: DatabaseConfig) -> Result<Self, DatabaseError> {
+        if config.backend != DatabaseBackend::Postgres {
+            return Err(DatabaseError::config(format!(
+                "backend {:?} is not supported by PgBotanicalDatabase; only Postgres connects here",
+                config.backend,
+            )));
+        }
+
+        let pool = PgPool::connect(&config.url).await?;
+
+        let database = Self { pool };
+        database.migrate().await?;
+        Ok(database)
+    }
+
+    /// Run database migrations to set up tables
+    pub async fn migrate(&self) -> Result<(), DatabaseError> {
+        crate::migrations::postgres::run_postgres_migrations(&self.pool).await
+    }
+
+    /// Check if the database connection is healthy
+    pub async fn health_check(&self) -> Result<(), DatabaseError> {
+        sqlx::query("SELECT 1").execute(&self.pool).await?;
+        Ok(())
+    }
+
+    /// Get a reference to the underlying connection pool
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// Close the database connection pool
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[async_trait::async_trait]
+impl Database for PgBotanicalDatabase {
+    fn backend(&self) -> DatabaseBackend {
+        DatabaseBackend::Postgres
+    }
+
+    async fn migrate(&self) -> Result<(), DatabaseError> {
+        PgBotanicalDatabase::migrate(self).await
+    }
+
+    async fn health_check(&self) -> Result<(), DatabaseError> {
+        PgBotanicalDatabase::health_check(self).await
+    }
+}
+
+/// Opens `config` against whichever backend it names, returning a boxed
+/// [`Database`] so callers can pick SQLite or Postgres at runtime from a
+/// single [`DatabaseConfig`] instead of hard-coding `BotanicalDatabase`.
+///
+/// Species CRUD and species search are the only surfaces implemented on
+/// both backends so far (`queries::species`/`queries::search` vs.
+/// `queries::species_pg`/`queries::search_pg`); reach for the concrete
+/// `BotanicalDatabase`/`PgBotanicalDatabase` type directly to get at their
+/// `pool()` for everything else.
+#[cfg(feature = "postgres")]
+pub async fn connect(config: DatabaseConfig) -> Result<Box<dyn Database>, DatabaseError> {
+    match config.backend {
+        DatabaseBackend::Sqlite => Ok(Box::new(BotanicalDatabase::new(config).await?)),
+        DatabaseBackend::Postgres => Ok(Box::new(PgBotanicalDatabase::new(config).await?)),
+        DatabaseBackend::MySql => Err(DatabaseError::config(
+            "backend MySql is not yet supported by connect(); only Sqlite and Postgres connect today",
+        )),
+    }
 }
\ No newline at end of file
@@ -48,10 +48,46 @@ impl std::error::Error for DatabaseError {
     }
 }
 
+/// SQLite result codes for a foreign-key violation (`SQLITE_CONSTRAINT_FOREIGNKEY`).
+const SQLITE_FOREIGN_KEY_CODES: &[&str] = &["787"];
+
+/// SQLite result codes for a uniqueness violation (`SQLITE_CONSTRAINT_PRIMARYKEY`/`_UNIQUE`).
+const SQLITE_UNIQUE_CODES: &[&str] = &["1555", "2067"];
+
+/// Postgres SQLSTATEs for `foreign_key_violation` / `unique_violation`.
+const POSTGRES_FOREIGN_KEY_CODE: &str = "23503";
+const POSTGRES_UNIQUE_CODE: &str = "23505";
+
 impl From<sqlx::Error> for DatabaseError {
     fn from(error: sqlx::Error) -> Self {
-        DatabaseError::SqlxError(error)
+        match error {
+            sqlx::Error::RowNotFound => DatabaseError::NotFound("row not found".to_string()),
+            sqlx::Error::Database(db_err) => classify_database_error(db_err),
+            other => DatabaseError::SqlxError(other),
+        }
+    }
+}
+
+/// Maps a driver-reported database error to the `DatabaseError` variant it
+/// represents, matching on the driver's structured error code rather than
+/// the human-readable message so classification stays stable across sqlite
+/// and Postgres (and across driver versions that might reword a message).
+fn classify_database_error(db_err: Box<dyn sqlx::error::DatabaseError>) -> DatabaseError {
+    let Some(code) = db_err.code() else {
+        return DatabaseError::SqlxError(sqlx::Error::Database(db_err));
+    };
+
+    let code = code.as_ref();
+
+    if SQLITE_FOREIGN_KEY_CODES.contains(&code) || code == POSTGRES_FOREIGN_KEY_CODE {
+        return DatabaseError::ConstraintViolation(format!("foreign key violation: {}", db_err.message()));
     }
+
+    if SQLITE_UNIQUE_CODES.contains(&code) || code == POSTGRES_UNIQUE_CODE {
+        return DatabaseError::ConstraintViolation(format!("unique violation: {}", db_err.message()));
+    }
+
+    DatabaseError::SqlxError(sqlx::Error::Database(db_err))
 }
 
 impl DatabaseError {
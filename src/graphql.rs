@@ -0,0 +1,188 @@
+//! GraphQL query surface for the taxonomy hierarchy.
+//!
+//! Exposes families, genera, and species as one nested GraphQL schema so
+//! clients fetch family -> genera -> species in a single round trip instead
+//! of chaining `queries::family`/`queries::genus`/`queries::species` calls.
+//! Query depth and complexity are capped so an abusive deeply-nested query
+//! can't hammer SQLite, and child lookups are routed through `DataLoader`s,
+//! built once in [`build_schema`] and shared by every request, to batch and
+//! dedupe lookups of the same parent that are issued together.
+
+use std::collections::HashMap;
+
+use async_graphql::dataloader::{DataLoader, Loader};
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Schema, SimpleObject};
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+use crate::queries::{family as family_queries, genus as genus_queries, species as species_queries};
+use crate::types::{Family as FamilyRow, Genus as GenusRow, Species as SpeciesRow};
+
+/// Default maximum nesting depth allowed for a single GraphQL query.
+pub const DEFAULT_MAX_DEPTH: usize = 8;
+
+/// Default maximum computed complexity allowed for a single GraphQL query.
+pub const DEFAULT_MAX_COMPLEXITY: usize = 200;
+
+fn graphql_error(err: crate::error::DatabaseError) -> async_graphql::Error {
+    async_graphql::Error::new(err.to_string())
+}
+
+/// GraphQL representation of a [`crate::types::Species`] - a leaf of the hierarchy.
+#[derive(SimpleObject, Clone)]
+pub struct Species {
+    pub id: Uuid,
+    pub specific_epithet: String,
+    pub authority: String,
+    pub publication_year: Option<i32>,
+    pub conservation_status: Option<String>,
+}
+
+impl From<SpeciesRow> for Species {
+    fn from(row: SpeciesRow) -> Self {
+        Self {
+            id: row.id,
+            specific_epithet: row.specific_epithet,
+            authority: row.authority,
+            publication_year: row.publication_year,
+            conservation_status: row.conservation_status,
+        }
+    }
+}
+
+/// GraphQL representation of a [`crate::types::Genus`], with its species
+/// resolved through a batched `DataLoader`.
+pub struct Genus(GenusRow);
+
+#[Object]
+impl Genus {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn authority(&self) -> &str {
+        &self.0.authority
+    }
+
+    #[graphql(complexity = "10 * child_complexity")]
+    async fn species(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Species>> {
+        let loader = ctx.data::<DataLoader<SpeciesByGenusLoader>>()?;
+        let species = loader.load_one(self.0.id).await?.unwrap_or_default();
+        Ok(species.into_iter().map(Species::from).collect())
+    }
+}
+
+/// GraphQL representation of a [`crate::types::Family`], with its genera
+/// resolved through a batched `DataLoader`.
+pub struct Family(FamilyRow);
+
+#[Object]
+impl Family {
+    async fn id(&self) -> Uuid {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn authority(&self) -> &str {
+        &self.0.authority
+    }
+
+    #[graphql(complexity = "10 * child_complexity")]
+    async fn genera(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<Genus>> {
+        let loader = ctx.data::<DataLoader<GeneraByFamilyLoader>>()?;
+        let genera = loader.load_one(self.0.id).await?.unwrap_or_default();
+        Ok(genera.into_iter().map(Genus).collect())
+    }
+}
+
+/// Batches genera lookups within one GraphQL request tick into a single
+/// `get_genera_by_family_ids` query instead of one query per family.
+pub struct GeneraByFamilyLoader {
+    pool: SqlitePool,
+}
+
+#[async_trait::async_trait]
+impl Loader<Uuid> for GeneraByFamilyLoader {
+    type Value = Vec<GenusRow>;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let genera = genus_queries::get_genera_by_family_ids(&self.pool, keys)
+            .await
+            .map_err(graphql_error)?;
+
+        let mut grouped: HashMap<Uuid, Self::Value> = HashMap::with_capacity(keys.len());
+        for genus in genera {
+            grouped.entry(genus.family_id).or_default().push(genus);
+        }
+        Ok(grouped)
+    }
+}
+
+/// Batches species lookups within one GraphQL request tick into a single
+/// `get_species_by_genus_ids` query instead of one query per genus.
+pub struct SpeciesByGenusLoader {
+    pool: SqlitePool,
+}
+
+#[async_trait::async_trait]
+impl Loader<Uuid> for SpeciesByGenusLoader {
+    type Value = Vec<SpeciesRow>;
+    type Error = async_graphql::Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
+        let species = species_queries::get_species_by_genus_ids(&self.pool, keys)
+            .await
+            .map_err(graphql_error)?;
+
+        let mut grouped: HashMap<Uuid, Self::Value> = HashMap::with_capacity(keys.len());
+        for item in species {
+            grouped.entry(item.genus_id).or_default().push(item);
+        }
+        Ok(grouped)
+    }
+}
+
+/// Root query type for the taxonomy schema.
+pub struct Query;
+
+#[Object]
+impl Query {
+    async fn family(&self, ctx: &Context<'_>, id: Uuid) -> async_graphql::Result<Option<Family>> {
+        let pool = ctx.data::<SqlitePool>()?;
+        let row = family_queries::get_family_by_id(pool, id).await.map_err(graphql_error)?;
+        Ok(row.map(Family))
+    }
+
+    async fn families_by_name(&self, ctx: &Context<'_>, name: String) -> async_graphql::Result<Vec<Family>> {
+        let pool = ctx.data::<SqlitePool>()?;
+        let rows = family_queries::get_families_by_name(pool, &name).await.map_err(graphql_error)?;
+        Ok(rows.into_iter().map(Family).collect())
+    }
+}
+
+/// The taxonomy GraphQL schema: a query-only root with no mutations or subscriptions.
+pub type BotanicaSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+/// Builds the taxonomy GraphQL schema against `pool`, registering data loaders
+/// that every request shares and capping query depth/complexity at
+/// `max_depth`/`max_complexity`.
+pub fn build_schema(pool: SqlitePool, max_depth: usize, max_complexity: usize) -> BotanicaSchema {
+    let genera_loader = DataLoader::new(GeneraByFamilyLoader { pool: pool.clone() }, tokio::spawn);
+    let species_loader = DataLoader::new(SpeciesByGenusLoader { pool: pool.clone() }, tokio::spawn);
+
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(max_depth)
+        .limit_complexity(max_complexity)
+        .data(pool)
+        .data(genera_loader)
+        .data(species_loader)
+        .finish()
+}
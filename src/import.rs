@@ -0,0 +1,110 @@
+//! Transactional batch import of taxonomy trees.
+//!
+//! `import_taxonomy` loads a full family -> genus -> species tree atomically:
+//! the whole import runs inside one transaction, and each family subtree runs
+//! inside its own nested savepoint, so a single bad family rolls back only
+//! that subtree instead of aborting everything already imported.
+
+use sqlx::{Acquire, Sqlite};
+
+use crate::error::DatabaseError;
+use crate::queries::{family, genus, species};
+use crate::types::{Family, Genus, Species};
+
+/// A genus and the species that belong to it, for import purposes.
+#[derive(Debug, Clone)]
+pub struct GenusImport {
+    pub genus: Genus,
+    pub species: Vec<Species>,
+}
+
+/// A family and its genera - the unit of work protected by one savepoint.
+#[derive(Debug, Clone)]
+pub struct FamilyImport {
+    pub family: Family,
+    pub genera: Vec<GenusImport>,
+}
+
+/// A full classification to import in one call to [`import_taxonomy`].
+///
+/// A plain alias rather than a new struct: `Vec<FamilyImport>` already is
+/// the bundle, and every `insert_*_tx` function in `queries::*` already
+/// takes `impl Acquire<'a, Database = Sqlite>` (satisfied by a `&SqlitePool`,
+/// an open `Transaction`, or a nested savepoint), so composing multi-step
+/// writes doesn't need a new transaction-generic CRUD surface here.
+pub type TaxonomyBundle = Vec<FamilyImport>;
+
+/// One family subtree that failed to import, with its 0-based index in
+/// `tree` and the reason.
+#[derive(Debug, Clone)]
+pub struct FamilyImportError {
+    pub family_index: usize,
+    pub message: String,
+}
+
+/// Count of rows [`import_taxonomy`] inserted, one field per rank, plus any
+/// family subtrees that failed and were skipped.
+#[derive(Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub families_imported: usize,
+    pub genera_imported: usize,
+    pub species_imported: usize,
+    pub errors: Vec<FamilyImportError>,
+}
+
+/// Imports `tree` atomically at the family level: one top-level transaction,
+/// with each family subtree wrapped in its own nested savepoint. A family
+/// subtree that fails has its savepoint rolled back and is recorded in the
+/// returned summary's `errors`; prior and subsequent families still commit,
+/// so a single bad family rolls back only that subtree instead of aborting
+/// everything already imported.
+pub async fn import_taxonomy<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    tree: &TaxonomyBundle,
+) -> Result<ImportSummary, DatabaseError> {
+    let mut conn = conn.acquire().await?;
+    let mut tx = conn.begin().await?;
+
+    let mut summary = ImportSummary::default();
+
+    for (family_index, family_import) in tree.iter().enumerate() {
+        let savepoint = tx.begin().await?;
+        match import_family_subtree(savepoint, family_import).await {
+            Ok((savepoint, family_summary)) => {
+                savepoint.commit().await?;
+                summary.families_imported += family_summary.families_imported;
+                summary.genera_imported += family_summary.genera_imported;
+                summary.species_imported += family_summary.species_imported;
+            }
+            Err(e) => {
+                // `savepoint` was moved into `import_family_subtree` and
+                // dropped there on its `Err` return, rolling back this
+                // family's rows without touching `tx` or earlier savepoints.
+                summary.errors.push(FamilyImportError { family_index, message: e.to_string() });
+            }
+        }
+    }
+
+    tx.commit().await?;
+    Ok(summary)
+}
+
+async fn import_family_subtree<'a>(
+    mut savepoint: sqlx::Transaction<'a, Sqlite>,
+    family_import: &FamilyImport,
+) -> Result<(sqlx::Transaction<'a, Sqlite>, ImportSummary), DatabaseError> {
+    family::insert_family_tx(&mut savepoint, &family_import.family).await?;
+    let mut summary = ImportSummary { families_imported: 1, ..Default::default() };
+
+    for genus_import in &family_import.genera {
+        genus::insert_genus_tx(&mut savepoint, &genus_import.genus).await?;
+        summary.genera_imported += 1;
+
+        for sp in &genus_import.species {
+            species::insert_species_tx(&mut savepoint, sp).await?;
+            summary.species_imported += 1;
+        }
+    }
+
+    Ok((savepoint, summary))
+}
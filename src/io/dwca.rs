@@ -0,0 +1,260 @@
+//! Darwin Core Archive (DwC-A) import/export for taxonomy bulk loading.
+//!
+//! A Darwin Core Archive is a zip file containing one or more CSV data files
+//! plus a `meta.xml` descriptor mapping columns to Darwin Core terms such as
+//! `dwc:family`, `dwc:genus`, `dwc:specificEpithet`, `dwc:scientificNameAuthorship`,
+//! and `dwc:namePublishedInYear`. This lets users ingest GBIF/World Flora
+//! exports directly instead of hand-building `Family::new`/`Genus::new`/`Species::new`.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use csv::StringRecord;
+use uuid::Uuid;
+use zip::ZipArchive;
+use zip::write::{FileOptions, ZipWriter};
+
+use crate::database::BotanicalDatabase;
+use crate::error::DatabaseError;
+use crate::queries::{family, genus, species};
+use crate::types::{Family, Genus, Species};
+
+/// One source row that failed to import, with its 0-based row number and reason.
+#[derive(Debug, Clone)]
+pub struct ImportError {
+    pub row_number: usize,
+    pub message: String,
+}
+
+/// Summary of a Darwin Core Archive import.
+#[derive(Debug, Clone, Default)]
+pub struct DwcaImportReport {
+    pub families_imported: usize,
+    pub genera_imported: usize,
+    pub species_imported: usize,
+    pub errors: Vec<ImportError>,
+}
+
+/// Placeholder authority recorded when a row (or the archive's core file,
+/// which has no family-level authority term) doesn't supply one, so rows
+/// still pass `Family`/`Genus` validation instead of being rejected outright.
+const UNKNOWN_AUTHORITY: &str = "Unknown";
+
+/// The Darwin Core columns this importer understands.
+struct DwcRow {
+    family: String,
+    genus: String,
+    specific_epithet: String,
+    authorship: String,
+    published_year: Option<i32>,
+    threat_status: Option<String>,
+}
+
+fn parse_row(headers: &StringRecord, record: &StringRecord) -> Result<DwcRow, String> {
+    let get = |term: &str| -> Option<String> {
+        headers
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case(term))
+            .and_then(|idx| record.get(idx))
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    Ok(DwcRow {
+        family: get("dwc:family").ok_or("missing dwc:family")?,
+        genus: get("dwc:genus").ok_or("missing dwc:genus")?,
+        specific_epithet: get("dwc:specificEpithet").ok_or("missing dwc:specificEpithet")?,
+        authorship: get("dwc:scientificNameAuthorship").unwrap_or_else(|| UNKNOWN_AUTHORITY.to_string()),
+        published_year: get("dwc:namePublishedInYear").and_then(|v| v.parse().ok()),
+        threat_status: get("dwc:threatStatus"),
+    })
+}
+
+/// Imports a Darwin Core Archive at `path`. Families and genera are
+/// resolved/created on first sight (deduplicated by name + authority), and
+/// `Species::conservation_status` is populated from `dwc:threatStatus`. The
+/// whole load runs inside one transaction; a malformed row is recorded in
+/// the report's `errors` rather than aborting the rest of the import.
+pub async fn import_dwca(db: &BotanicalDatabase, path: &Path) -> Result<DwcaImportReport, DatabaseError> {
+    let file = File::open(path).map_err(|e| DatabaseError::validation(format!("failed to open archive: {}", e)))?;
+    let mut archive =
+        ZipArchive::new(file).map_err(|e| DatabaseError::validation(format!("invalid DwC-A archive: {}", e)))?;
+
+    let core_name = find_core_data_file(&archive)?;
+    let csv_bytes = {
+        let mut core_file = archive
+            .by_name(&core_name)
+            .map_err(|e| DatabaseError::validation(format!("missing core data file {}: {}", core_name, e)))?;
+        let mut buf = Vec::new();
+        std::io::copy(&mut core_file, &mut buf)
+            .map_err(|e| DatabaseError::validation(format!("failed to read {}: {}", core_name, e)))?;
+        buf
+    };
+
+    let mut reader = csv::ReaderBuilder::new().has_headers(true).from_reader(csv_bytes.as_slice());
+    let headers = reader
+        .headers()
+        .map_err(|e| DatabaseError::validation(e.to_string()))?
+        .clone();
+
+    let mut report = DwcaImportReport::default();
+    let mut families: HashMap<(String, String), Uuid> = HashMap::new();
+    let mut genera: HashMap<(Uuid, String, String), Uuid> = HashMap::new();
+
+    let mut tx = db.transaction().await?;
+
+    for (row_number, record) in reader.records().enumerate() {
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                report.errors.push(ImportError { row_number, message: e.to_string() });
+                continue;
+            }
+        };
+
+        let row = match parse_row(&headers, &record) {
+            Ok(r) => r,
+            Err(message) => {
+                report.errors.push(ImportError { row_number, message });
+                continue;
+            }
+        };
+
+        let family_authority = UNKNOWN_AUTHORITY.to_string();
+        let family_key = (row.family.clone(), family_authority.clone());
+        let family_id = if let Some(&id) = families.get(&family_key) {
+            id
+        } else {
+            let new_family = Family::new(row.family.clone(), family_authority);
+            if let Err(e) = family::insert_family_tx(&mut tx, &new_family).await {
+                report.errors.push(ImportError { row_number, message: e.to_string() });
+                continue;
+            }
+            families.insert(family_key, new_family.id);
+            report.families_imported += 1;
+            new_family.id
+        };
+
+        let genus_key = (family_id, row.genus.clone(), row.authorship.clone());
+        let genus_id = if let Some(&id) = genera.get(&genus_key) {
+            id
+        } else {
+            let new_genus = Genus::new(family_id, row.genus.clone(), row.authorship.clone());
+            if let Err(e) = genus::insert_genus_tx(&mut tx, &new_genus).await {
+                report.errors.push(ImportError { row_number, message: e.to_string() });
+                continue;
+            }
+            genera.insert(genus_key, new_genus.id);
+            report.genera_imported += 1;
+            new_genus.id
+        };
+
+        let new_species = Species::new(
+            genus_id,
+            row.specific_epithet,
+            row.authorship,
+            row.published_year,
+            row.threat_status,
+        );
+        if let Err(e) = species::insert_species_tx(&mut tx, &new_species).await {
+            report.errors.push(ImportError { row_number, message: e.to_string() });
+            continue;
+        }
+        report.species_imported += 1;
+    }
+
+    tx.commit().await?;
+    Ok(report)
+}
+
+/// Locates the core data file by name: the first archive entry named
+/// `occurrence.txt` or `taxon.txt` (case-insensitive). `meta.xml` is not read.
+fn find_core_data_file(archive: &ZipArchive<File>) -> Result<String, DatabaseError> {
+    for name in archive.file_names() {
+        if name.eq_ignore_ascii_case("occurrence.txt") || name.eq_ignore_ascii_case("taxon.txt") {
+            return Ok(name.to_string());
+        }
+    }
+
+    Err(DatabaseError::validation(
+        "could not locate a core data file (expected occurrence.txt or taxon.txt) in the archive",
+    ))
+}
+
+/// Exports the full family/genus/species hierarchy as a Darwin Core Archive
+/// (a single `taxon.txt` CSV plus a minimal `meta.xml` descriptor) at `path`.
+pub async fn export_dwca(db: &BotanicalDatabase, path: &Path) -> Result<(), DatabaseError> {
+    let families = family::get_families_by_name(db.pool(), "").await?;
+
+    let mut csv_writer = csv::WriterBuilder::new().has_headers(true).from_writer(Vec::new());
+    csv_writer
+        .write_record([
+            "dwc:family",
+            "dwc:genus",
+            "dwc:specificEpithet",
+            "dwc:scientificNameAuthorship",
+            "dwc:namePublishedInYear",
+            "dwc:threatStatus",
+        ])
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    for fam in &families {
+        let genera = genus::get_genera_by_family_id(db.pool(), fam.id).await?;
+        for gen in &genera {
+            let species_rows = species::get_species_by_genus_id(db.pool(), gen.id).await?;
+            for sp in &species_rows {
+                csv_writer
+                    .write_record([
+                        fam.name.as_str(),
+                        gen.name.as_str(),
+                        sp.specific_epithet.as_str(),
+                        sp.authority.as_str(),
+                        &sp.publication_year.map(|y| y.to_string()).unwrap_or_default(),
+                        sp.conservation_status.as_deref().unwrap_or(""),
+                    ])
+                    .map_err(|e| DatabaseError::validation(e.to_string()))?;
+            }
+        }
+    }
+
+    let csv_bytes = csv_writer
+        .into_inner()
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    let meta_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<archive xmlns="http://rs.tdwg.org/dwc/text/" metadata="eml.xml">
+  <core encoding="UTF-8" fieldsTerminatedBy="," linesTerminatedBy="\n" fieldsEnclosedBy="&quot;" ignoreHeaderLines="1" rowType="http://rs.tdwg.org/dwc/terms/Taxon">
+    <files><location>taxon.txt</location></files>
+    <field index="0" term="http://rs.tdwg.org/dwc/terms/family"/>
+    <field index="1" term="http://rs.tdwg.org/dwc/terms/genus"/>
+    <field index="2" term="http://rs.tdwg.org/dwc/terms/specificEpithet"/>
+    <field index="3" term="http://rs.tdwg.org/dwc/terms/scientificNameAuthorship"/>
+    <field index="4" term="http://rs.tdwg.org/dwc/terms/namePublishedInYear"/>
+    <field index="5" term="http://rs.tdwg.org/dwc/terms/threatStatus"/>
+  </core>
+</archive>
+"#;
+
+    let out_file = File::create(path).map_err(|e| DatabaseError::validation(format!("failed to create archive: {}", e)))?;
+    let mut writer = ZipWriter::new(out_file);
+    let options = FileOptions::default();
+
+    writer
+        .start_file("taxon.txt", options)
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+    writer
+        .write_all(&csv_bytes)
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    writer
+        .start_file("meta.xml", options)
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+    writer
+        .write_all(meta_xml.as_bytes())
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    writer.finish().map_err(|e| DatabaseError::validation(e.to_string()))?;
+    Ok(())
+}
@@ -0,0 +1,5 @@
+//! Bulk import/export of taxonomy data in standard interchange formats.
+
+pub mod dwca;
+
+pub use dwca::{export_dwca, import_dwca, DwcaImportReport, ImportError};
@@ -8,31 +8,38 @@ pub mod types;
 pub mod queries;
 pub mod migrations;
 pub mod error;
+pub mod import;
+pub mod sync;
+pub mod io;
+pub mod slug;
+pub mod query_builder;
 
 #[cfg(feature = "contextlite")]
 pub mod contextlite;
 
+#[cfg(feature = "graphql")]
+pub mod graphql;
+
 // Re-exports for convenience
 pub use database::{BotanicalDatabase, DatabaseConfig};
 pub use error::DatabaseError;
 pub use types::{Species, Genus, Family};
 
+#[cfg(feature = "postgres")]
+pub use database::{connect, PgBotanicalDatabase};
+
 /// Result type alias for convenient error handling
 pub type Result<T> = std::result::Result<T, DatabaseError>;
 
 /// Initialize a new botanical database with migrations
 pub async fn initialize_database(database_url: &str) -> Result<BotanicalDatabase> {
     let config = DatabaseConfig::file(database_url);
-    let database = BotanicalDatabase::new(config).await?;
-    database.migrate().await?;
-    Ok(database)
+    BotanicalDatabase::new(config).await
 }
 
 /// Create an in-memory database for testing
 pub async fn create_test_database() -> Result<BotanicalDatabase> {
-    let database = BotanicalDatabase::memory().await?;
-    database.migrate().await?;
-    Ok(database)
+    BotanicalDatabase::memory().await
 }
 
 // Test modules - only compiled when testing
@@ -1,65 +1,723 @@
-use sqlx::{SqlitePool, query};
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+
 use crate::error::DatabaseError;
 
 pub mod runner;
 pub mod schemas;
 
+#[cfg(feature = "postgres")]
+pub mod postgres;
 
-/// Initialize the database with all required tables
-pub async fn run_migrations(pool: &SqlitePool) -> Result<(), DatabaseError> {
-    // Create families table
-    query(r#"
-        CREATE TABLE IF NOT EXISTS families (
-            id TEXT PRIMARY KEY,
-            name TEXT NOT NULL,
-            authority TEXT
-        )
-    "#)
-    .execute(pool)
-    .await?;
+/// One schema change, identified by a monotonically increasing `version`.
+///
+/// `statements` runs in order the first time a migration is applied. Once
+/// applied, its `checksum` (over the concatenated statements) is recorded in
+/// the `migrations` table, so a later edit to `MIGRATIONS` that changes an
+/// already-applied migration's SQL is caught as drift instead of silently
+/// never re-running. `down` reverses `statements` and is only used by
+/// [`migrate_to`]/[`rollback`] when stepping backward past this version.
+struct Migration {
+    version: i64,
+    name: &'static str,
+    statements: &'static [&'static str],
+    down: &'static [&'static str],
+}
 
-    // Create genera table
-    query(r#"
-        CREATE TABLE IF NOT EXISTS genera (
-            id TEXT PRIMARY KEY,
-            family_id TEXT NOT NULL,
+/// The ordered set of schema changes applied by [`run_migrations`].
+///
+/// Existing entries must never be edited in place once released; add a new
+/// migration with the next version instead, the same way a `down`/`up` pair
+/// would be added to any other migration tool.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_families",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS families (
+                id TEXT PRIMARY KEY,
+                name TEXT NOT NULL,
+                authority TEXT
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS families"],
+    },
+    Migration {
+        version: 2,
+        name: "create_genera",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS genera (
+                id TEXT PRIMARY KEY,
+                family_id TEXT NOT NULL,
+                name TEXT NOT NULL,
+                authority TEXT,
+                FOREIGN KEY (family_id) REFERENCES families(id)
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS genera"],
+    },
+    Migration {
+        version: 3,
+        name: "create_species",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS species (
+                id TEXT PRIMARY KEY,
+                genus_id TEXT NOT NULL,
+                specific_epithet TEXT NOT NULL,
+                authority TEXT,
+                publication_year INTEGER,
+                conservation_status TEXT,
+                FOREIGN KEY (genus_id) REFERENCES genera(id)
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS species"],
+    },
+    Migration {
+        version: 4,
+        name: "create_specimens",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS specimens (
+                id TEXT PRIMARY KEY,
+                species_id TEXT NOT NULL,
+                collector TEXT,
+                collection_date TEXT,
+                location TEXT,
+                notes TEXT,
+                FOREIGN KEY (species_id) REFERENCES species(id)
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS specimens"],
+    },
+    Migration {
+        version: 5,
+        name: "create_botanical_vectors",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS botanical_vectors (
+                document_id TEXT NOT NULL,
+                chunk_index INTEGER NOT NULL,
+                source TEXT NOT NULL,
+                text TEXT NOT NULL,
+                embedding BLOB NOT NULL,
+                PRIMARY KEY (document_id, chunk_index)
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS botanical_vectors"],
+    },
+    Migration {
+        version: 6,
+        name: "create_cultivation_records",
+        statements: &[
+            r#"
+            CREATE TABLE IF NOT EXISTS cultivation_records (
+                id TEXT PRIMARY KEY,
+                species_id TEXT NOT NULL,
+                growth_stage TEXT NOT NULL,
+                environment_id TEXT,
+                notes TEXT,
+                photos TEXT NOT NULL DEFAULT '[]',
+                recorded_at TEXT NOT NULL,
+                cultivator TEXT NOT NULL,
+                FOREIGN KEY (species_id) REFERENCES species(id)
+            )
+            "#,
+            r#"
+            CREATE INDEX IF NOT EXISTS idx_cultivation_records_recorded_at
+            ON cultivation_records (species_id, recorded_at)
+            "#,
+        ],
+        down: &[
+            "DROP INDEX IF EXISTS idx_cultivation_records_recorded_at",
+            "DROP TABLE IF EXISTS cultivation_records",
+        ],
+    },
+    Migration {
+        version: 7,
+        name: "create_sync_metadata",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS sync_metadata (
+                source TEXT PRIMARY KEY,
+                high_water_mark INTEGER NOT NULL DEFAULT 0,
+                updated_at TEXT NOT NULL
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS sync_metadata"],
+    },
+    Migration {
+        version: 8,
+        name: "create_synced_taxa",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS synced_taxa (
+                remote_id TEXT PRIMARY KEY,
+                rank TEXT NOT NULL,
+                local_id TEXT NOT NULL,
+                version INTEGER NOT NULL
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS synced_taxa"],
+    },
+    Migration {
+        version: 9,
+        name: "create_taxonomy_fts",
+        statements: &[
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS taxonomy_fts USING fts5(
+                entity_id UNINDEXED,
+                rank UNINDEXED,
+                name,
+                authority
+            )
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS families_fts_insert AFTER INSERT ON families BEGIN
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'family', new.name, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS families_fts_update AFTER UPDATE ON families BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'family';
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'family', new.name, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS families_fts_delete AFTER DELETE ON families BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'family';
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS genera_fts_insert AFTER INSERT ON genera BEGIN
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'genus', new.name, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS genera_fts_update AFTER UPDATE ON genera BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'genus';
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'genus', new.name, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS genera_fts_delete AFTER DELETE ON genera BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'genus';
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS species_fts_insert AFTER INSERT ON species BEGIN
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'species', new.specific_epithet, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS species_fts_update AFTER UPDATE ON species BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'species';
+                INSERT INTO taxonomy_fts (entity_id, rank, name, authority) VALUES (new.id, 'species', new.specific_epithet, new.authority);
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS species_fts_delete AFTER DELETE ON species BEGIN
+                DELETE FROM taxonomy_fts WHERE entity_id = old.id AND rank = 'species';
+            END
+            "#,
+        ],
+        down: &[
+            "DROP TRIGGER IF EXISTS families_fts_insert",
+            "DROP TRIGGER IF EXISTS families_fts_update",
+            "DROP TRIGGER IF EXISTS families_fts_delete",
+            "DROP TRIGGER IF EXISTS genera_fts_insert",
+            "DROP TRIGGER IF EXISTS genera_fts_update",
+            "DROP TRIGGER IF EXISTS genera_fts_delete",
+            "DROP TRIGGER IF EXISTS species_fts_insert",
+            "DROP TRIGGER IF EXISTS species_fts_update",
+            "DROP TRIGGER IF EXISTS species_fts_delete",
+            "DROP TABLE IF EXISTS taxonomy_fts",
+        ],
+    },
+    Migration {
+        version: 10,
+        name: "create_environments",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS environments (
+                id TEXT PRIMARY KEY,
+                temperature_celsius REAL,
+                humidity_percent REAL,
+                ph_level REAL,
+                light_hours REAL,
+                co2_ppm INTEGER,
+                recorded_at TEXT NOT NULL
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS environments"],
+    },
+    Migration {
+        version: 11,
+        name: "create_cultivation_journal",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS cultivation_journal (
+                id TEXT PRIMARY KEY,
+                species_id TEXT NOT NULL,
+                record_id TEXT NOT NULL,
+                sequence INTEGER NOT NULL,
+                prev_hash TEXT NOT NULL,
+                entry_hash TEXT NOT NULL,
+                recorded_at TEXT NOT NULL,
+                FOREIGN KEY (species_id) REFERENCES species(id),
+                FOREIGN KEY (record_id) REFERENCES cultivation_records(id),
+                UNIQUE (species_id, sequence)
+            )
+        "#],
+        down: &["DROP TABLE IF EXISTS cultivation_journal"],
+    },
+    Migration {
+        version: 12,
+        name: "create_taxa",
+        statements: &[r#"
+            CREATE TABLE IF NOT EXISTS taxa (
+                id TEXT PRIMARY KEY,
+                parent_id TEXT,
+                rank TEXT NOT NULL,
+                name TEXT NOT NULL,
+                authority TEXT NOT NULL,
+                FOREIGN KEY (parent_id) REFERENCES taxa(id)
+            )
+        "#, r#"
+            CREATE INDEX IF NOT EXISTS idx_taxa_parent_id ON taxa(parent_id)
+        "#],
+        down: &["DROP TABLE IF EXISTS taxa"],
+    },
+    Migration {
+        version: 13,
+        name: "create_taxa_fts",
+        statements: &[
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS taxa_fts USING fts5(
+                species_id UNINDEXED,
+                epithet,
+                genus,
+                family,
+                authority
+            )
+            "#,
+            // Backfills rows that existed before this migration; a no-op on
+            // a fresh database where `species` is still empty.
+            r#"
+            INSERT INTO taxa_fts (species_id, epithet, genus, family, authority)
+            SELECT s.id, s.specific_epithet, g.name, f.name, s.authority
+            FROM species s
+            JOIN genera g ON g.id = s.genus_id
+            JOIN families f ON f.id = g.family_id
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS taxa_fts_species_insert AFTER INSERT ON species BEGIN
+                INSERT INTO taxa_fts (species_id, epithet, genus, family, authority)
+                SELECT new.id, new.specific_epithet, g.name, f.name, new.authority
+                FROM genera g JOIN families f ON f.id = g.family_id
+                WHERE g.id = new.genus_id;
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS taxa_fts_species_update AFTER UPDATE ON species BEGIN
+                DELETE FROM taxa_fts WHERE species_id = old.id;
+                INSERT INTO taxa_fts (species_id, epithet, genus, family, authority)
+                SELECT new.id, new.specific_epithet, g.name, f.name, new.authority
+                FROM genera g JOIN families f ON f.id = g.family_id
+                WHERE g.id = new.genus_id;
+            END
+            "#,
+            r#"
+            CREATE TRIGGER IF NOT EXISTS taxa_fts_species_delete AFTER DELETE ON species BEGIN
+                DELETE FROM taxa_fts WHERE species_id = old.id;
+            END
+            "#,
+            // A genus rename must refresh every species row denormalized
+            // under it, not just rows inserted/updated after the rename.
+            r#"
+            CREATE TRIGGER IF NOT EXISTS taxa_fts_genus_update AFTER UPDATE ON genera BEGIN
+                UPDATE taxa_fts SET genus = new.name
+                WHERE species_id IN (SELECT id FROM species WHERE genus_id = new.id);
+            END
+            "#,
+            // Same, one level up: a family rename must refresh every species
+            // row beneath every genus in that family.
+            r#"
+            CREATE TRIGGER IF NOT EXISTS taxa_fts_family_update AFTER UPDATE ON families BEGIN
+                UPDATE taxa_fts SET family = new.name
+                WHERE species_id IN (
+                    SELECT s.id FROM species s JOIN genera g ON g.id = s.genus_id WHERE g.family_id = new.id
+                );
+            END
+            "#,
+        ],
+        down: &[
+            "DROP TRIGGER IF EXISTS taxa_fts_species_insert",
+            "DROP TRIGGER IF EXISTS taxa_fts_species_update",
+            "DROP TRIGGER IF EXISTS taxa_fts_species_delete",
+            "DROP TRIGGER IF EXISTS taxa_fts_genus_update",
+            "DROP TRIGGER IF EXISTS taxa_fts_family_update",
+            "DROP TABLE IF EXISTS taxa_fts",
+        ],
+    },
+    Migration {
+        version: 14,
+        name: "add_slug_columns",
+        statements: &[
+            "ALTER TABLE families ADD COLUMN slug TEXT",
+            "ALTER TABLE genera ADD COLUMN slug TEXT",
+            "ALTER TABLE species ADD COLUMN slug TEXT",
+            // Best-effort backfill for rows that predate this migration,
+            // using SQLite's own LOWER()/REPLACE() rather than the Rust
+            // `slug::slugify` and the disambiguation loop the insert
+            // functions use.
+            // It cannot guarantee collision-free slugs for pre-existing
+            // duplicate names, so the index below is not UNIQUE; uniqueness
+            // for rows inserted from here on is instead enforced by each
+            // insert function's own disambiguation loop.
+            "UPDATE families SET slug = REPLACE(LOWER(TRIM(name)), ' ', '-') WHERE slug IS NULL",
+            "UPDATE genera SET slug = REPLACE(LOWER(TRIM(name)), ' ', '-') WHERE slug IS NULL",
+            "UPDATE species SET slug = REPLACE(LOWER(TRIM(specific_epithet)), ' ', '-') WHERE slug IS NULL",
+            "CREATE INDEX IF NOT EXISTS idx_families_slug ON families(slug)",
+            "CREATE INDEX IF NOT EXISTS idx_genera_slug ON genera(slug)",
+            "CREATE INDEX IF NOT EXISTS idx_species_slug ON species(slug)",
+        ],
+        down: &[
+            "DROP INDEX IF EXISTS idx_families_slug",
+            "DROP INDEX IF EXISTS idx_genera_slug",
+            "DROP INDEX IF EXISTS idx_species_slug",
+            // Requires SQLite >= 3.35 (bundled by the `sqlx` "sqlite" feature).
+            "ALTER TABLE families DROP COLUMN slug",
+            "ALTER TABLE genera DROP COLUMN slug",
+            "ALTER TABLE species DROP COLUMN slug",
+        ],
+    },
+    Migration {
+        version: 15,
+        name: "split_specimen_location",
+        statements: &[
+            "ALTER TABLE specimens ADD COLUMN latitude REAL",
+            "ALTER TABLE specimens ADD COLUMN longitude REAL",
+            "ALTER TABLE specimens ADD COLUMN elevation REAL",
+            // The free-text `location` column predates structured
+            // coordinates and can't be parsed back into them reliably, so
+            // it is dropped rather than backfilled; existing specimens keep
+            // their collector/date/notes and simply start with no
+            // coordinates until re-georeferenced.
+            "ALTER TABLE specimens DROP COLUMN location",
+        ],
+        down: &[
+            "ALTER TABLE specimens ADD COLUMN location TEXT",
+            "ALTER TABLE specimens DROP COLUMN elevation",
+            "ALTER TABLE specimens DROP COLUMN longitude",
+            "ALTER TABLE specimens DROP COLUMN latitude",
+        ],
+    },
+    Migration {
+        version: 16,
+        name: "generalize_cultivation_journal",
+        statements: &[
+            // `cultivation_journal` only ever recorded `CultivationRecord`
+            // entries and had no `prev_id`/`heads` pointer chain, just
+            // `sequence`. Both need a column that didn't exist before, and
+            // the old `record_id` FK only pointed at `cultivation_records`,
+            // which can't hold an `Environment` row's id - so the table is
+            // rebuilt rather than altered in place. `entity_id` is left
+            // without a FK since it now points at either
+            // `cultivation_records` or `environments` depending on
+            // `entity_kind`.
+            r#"
+            CREATE TABLE cultivation_journal_new (
+                id TEXT PRIMARY KEY,
+                species_id TEXT NOT NULL,
+                entity_id TEXT NOT NULL,
+                entity_kind TEXT NOT NULL DEFAULT 'cultivation_record',
+                sequence INTEGER NOT NULL,
+                prev_id TEXT,
+                prev_hash TEXT NOT NULL,
+                entry_hash TEXT NOT NULL,
+                recorded_at TEXT NOT NULL,
+                FOREIGN KEY (species_id) REFERENCES species(id),
+                UNIQUE (species_id, sequence)
+            )
+            "#,
+            "INSERT INTO cultivation_journal_new (id, species_id, entity_id, entity_kind, sequence, prev_id, prev_hash, entry_hash, recorded_at) \
+             SELECT id, species_id, record_id, 'cultivation_record', sequence, NULL, prev_hash, entry_hash, recorded_at FROM cultivation_journal",
+            "DROP TABLE cultivation_journal",
+            "ALTER TABLE cultivation_journal_new RENAME TO cultivation_journal",
+            // Backfill prev_id from the sequence chain for rows that predate
+            // it; `heads` is backfilled next from whichever row is nobody's
+            // prev_id.
+            r#"
+            UPDATE cultivation_journal AS cur
+            SET prev_id = (
+                SELECT prev.id FROM cultivation_journal prev
+                WHERE prev.species_id = cur.species_id AND prev.sequence = cur.sequence - 1
+            )
+            WHERE cur.sequence > 0
+            "#,
+            r#"
+            CREATE TABLE IF NOT EXISTS cultivation_journal_heads (
+                species_id TEXT PRIMARY KEY,
+                entry_id TEXT NOT NULL REFERENCES cultivation_journal(id)
+            )
+            "#,
+            r#"
+            INSERT INTO cultivation_journal_heads (species_id, entry_id)
+            SELECT cur.species_id, cur.id FROM cultivation_journal cur
+            WHERE NOT EXISTS (SELECT 1 FROM cultivation_journal nxt WHERE nxt.prev_id = cur.id)
+            "#,
+        ],
+        down: &[
+            "DROP TABLE IF EXISTS cultivation_journal_heads",
+            r#"
+            CREATE TABLE cultivation_journal_old (
+                id TEXT PRIMARY KEY,
+                species_id TEXT NOT NULL,
+                record_id TEXT NOT NULL,
+                sequence INTEGER NOT NULL,
+                prev_hash TEXT NOT NULL,
+                entry_hash TEXT NOT NULL,
+                recorded_at TEXT NOT NULL,
+                FOREIGN KEY (species_id) REFERENCES species(id),
+                FOREIGN KEY (record_id) REFERENCES cultivation_records(id),
+                UNIQUE (species_id, sequence)
+            )
+            "#,
+            // Environment-reading entries have no home in the old schema
+            // and are dropped on downgrade, same as any other column that
+            // can't round-trip through an earlier migration version.
+            "INSERT INTO cultivation_journal_old (id, species_id, record_id, sequence, prev_hash, entry_hash, recorded_at) \
+             SELECT id, species_id, entity_id, sequence, prev_hash, entry_hash, recorded_at FROM cultivation_journal WHERE entity_kind = 'cultivation_record'",
+            "DROP TABLE cultivation_journal",
+            "ALTER TABLE cultivation_journal_old RENAME TO cultivation_journal",
+        ],
+    },
+    Migration {
+        version: 17,
+        name: "enforce_unique_slugs",
+        statements: &[
+            // `add_slug_columns` (v14) deliberately left the slug index
+            // non-unique because pre-existing duplicate names could collide
+            // under the backfill's plain LOWER()/REPLACE() slugify. Before a
+            // UNIQUE index can be added, disambiguate any such survivors by
+            // appending `-<n>` to every occurrence but the lowest-rowid one,
+            // the same suffix scheme the insert functions' `unique_*_slug`
+            // disambiguation loops use.
+            r#"
+            UPDATE families SET slug = slug || '-' || (
+                1 + (SELECT COUNT(*) FROM families earlier WHERE earlier.slug = families.slug AND earlier.rowid < families.rowid)
+            )
+            WHERE (SELECT COUNT(*) FROM families earlier WHERE earlier.slug = families.slug AND earlier.rowid < families.rowid) > 0
+            "#,
+            r#"
+            UPDATE genera SET slug = slug || '-' || (
+                1 + (SELECT COUNT(*) FROM genera earlier WHERE earlier.slug = genera.slug AND earlier.rowid < genera.rowid)
+            )
+            WHERE (SELECT COUNT(*) FROM genera earlier WHERE earlier.slug = genera.slug AND earlier.rowid < genera.rowid) > 0
+            "#,
+            r#"
+            UPDATE species SET slug = slug || '-' || (
+                1 + (SELECT COUNT(*) FROM species earlier WHERE earlier.slug = species.slug AND earlier.rowid < species.rowid)
+            )
+            WHERE (SELECT COUNT(*) FROM species earlier WHERE earlier.slug = species.slug AND earlier.rowid < species.rowid) > 0
+            "#,
+            "DROP INDEX IF EXISTS idx_families_slug",
+            "DROP INDEX IF EXISTS idx_genera_slug",
+            "DROP INDEX IF EXISTS idx_species_slug",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_families_slug ON families(slug)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_genera_slug ON genera(slug)",
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_species_slug ON species(slug)",
+        ],
+        down: &[
+            "DROP INDEX IF EXISTS idx_families_slug",
+            "DROP INDEX IF EXISTS idx_genera_slug",
+            "DROP INDEX IF EXISTS idx_species_slug",
+            "CREATE INDEX IF NOT EXISTS idx_families_slug ON families(slug)",
+            "CREATE INDEX IF NOT EXISTS idx_genera_slug ON genera(slug)",
+            "CREATE INDEX IF NOT EXISTS idx_species_slug ON species(slug)",
+            // The disambiguating renames above are not reverted - they keep
+            // slugs valid and unique, which a downgrade has no reason to undo.
+        ],
+    },
+];
+
+/// 64-bit FNV-1a over `data`, formatted as lowercase hex.
+///
+/// Used instead of `std::hash::DefaultHasher` because its algorithm is not
+/// guaranteed stable across Rust releases, which would make checksums
+/// recorded by an older build mismatch under a newer compiler even though
+/// the migration SQL never changed.
+fn fnv1a_hex(data: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for &byte in data {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    format!("{:016x}", hash)
+}
+
+fn migration_checksum(migration: &Migration) -> String {
+    fnv1a_hex(migration.statements.concat().as_bytes())
+}
+
+async fn ensure_migrations_table(pool: &SqlitePool) -> Result<(), DatabaseError> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS migrations (
+            version INTEGER PRIMARY KEY,
             name TEXT NOT NULL,
-            authority TEXT,
-            FOREIGN KEY (family_id) REFERENCES families(id)
+            checksum TEXT NOT NULL,
+            applied_at TEXT NOT NULL
         )
-    "#)
+        "#,
+    )
     .execute(pool)
     .await?;
 
-    // Create species table
-    query(r#"
-        CREATE TABLE IF NOT EXISTS species (
-            id TEXT PRIMARY KEY,
-            genus_id TEXT NOT NULL,
-            specific_epithet TEXT NOT NULL,
-            authority TEXT,
-            publication_year INTEGER,
-            conservation_status TEXT,
-            FOREIGN KEY (genus_id) REFERENCES genera(id)
-        )
-    "#)
-    .execute(pool)
-    .await?;
+    Ok(())
+}
 
-    // Create specimens table
-    query(r#"
-        CREATE TABLE IF NOT EXISTS specimens (
-            id TEXT PRIMARY KEY,
-            species_id TEXT NOT NULL,
-            collector TEXT,
-            collection_date TEXT,
-            location TEXT,
-            notes TEXT,
-            FOREIGN KEY (species_id) REFERENCES species(id)
-        )
-    "#)
-    .execute(pool)
-    .await?;
+async fn applied_migrations(pool: &SqlitePool) -> Result<HashMap<i64, String>, DatabaseError> {
+    let applied = sqlx::query("SELECT version, checksum FROM migrations")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .map(|row| (row.get::<i64, _>("version"), row.get::<String, _>("checksum")))
+        .collect();
+
+    Ok(applied)
+}
+
+/// Fails if any migration already recorded as applied no longer matches its
+/// checksum in [`MIGRATIONS`] (i.e. a released migration's SQL was edited in
+/// place instead of appending a new version).
+fn verify_no_drift(applied: &HashMap<i64, String>) -> Result<(), DatabaseError> {
+    for migration in MIGRATIONS {
+        if let Some(recorded_checksum) = applied.get(&migration.version) {
+            let checksum = migration_checksum(migration);
+            if recorded_checksum != &checksum {
+                return Err(DatabaseError::migration(format!(
+                    "migration {} ({}) has already been applied with checksum {} but its SQL now computes to {}; \
+                     add a new migration instead of editing an applied one",
+                    migration.version, migration.name, recorded_checksum, checksum
+                )));
+            }
+        }
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Initialize the database by applying every migration in [`MIGRATIONS`]
+/// that has not already run, in order, tracking progress in a `migrations`
+/// table keyed by version.
+///
+/// Each applied migration's checksum is recorded alongside it; if a
+/// migration already marked applied no longer matches its recorded
+/// checksum (i.e. `MIGRATIONS` was edited in place instead of appending a
+/// new version), this returns a [`DatabaseError::migration`] rather than
+/// silently skipping or re-running it.
+pub async fn run_migrations(pool: &SqlitePool) -> Result<(), DatabaseError> {
+    let target = MIGRATIONS.last().map(|m| m.version).unwrap_or(0);
+    migrate_to(pool, target).await
+}
+
+/// Returns the highest migration version recorded as applied, or `0` if
+/// none have run yet.
+pub async fn schema_version(pool: &SqlitePool) -> Result<i64, DatabaseError> {
+    ensure_migrations_table(pool).await?;
+
+    let applied = applied_migrations(pool).await?;
+    Ok(applied.keys().copied().max().unwrap_or(0))
+}
+
+/// Steps the schema to exactly `target_version`, applying pending `up`
+/// migrations in order if `target_version` is ahead of the current schema,
+/// or running `down` scripts in reverse order if it is behind.
+///
+/// Each individual migration (forward or reverse) runs inside its own
+/// transaction, so a failing step leaves the schema at the last
+/// successfully applied/reverted version rather than partially migrated.
+/// Already-applied migrations are checksum-verified before anything else
+/// runs, the same way [`run_migrations`] does.
+pub async fn migrate_to(pool: &SqlitePool, target_version: i64) -> Result<(), DatabaseError> {
+    ensure_migrations_table(pool).await?;
+
+    let mut applied = applied_migrations(pool).await?;
+    verify_no_drift(&applied)?;
+
+    let current_version = applied.keys().copied().max().unwrap_or(0);
+
+    if target_version > current_version {
+        for migration in MIGRATIONS {
+            if migration.version <= current_version || migration.version > target_version {
+                continue;
+            }
+
+            let checksum = migration_checksum(migration);
+            let mut tx = pool.begin().await?;
+
+            for statement in migration.statements {
+                sqlx::query(statement).execute(&mut *tx).await?;
+            }
+
+            sqlx::query("INSERT INTO migrations (version, name, checksum, applied_at) VALUES (?, ?, ?, ?)")
+                .bind(migration.version)
+                .bind(migration.name)
+                .bind(&checksum)
+                .bind(Utc::now().to_rfc3339())
+                .execute(&mut *tx)
+                .await?;
+
+            tx.commit().await?;
+            applied.insert(migration.version, checksum);
+        }
+    } else if target_version < current_version {
+        for migration in MIGRATIONS.iter().rev() {
+            if migration.version > current_version || migration.version <= target_version {
+                continue;
+            }
+
+            if migration.down.is_empty() {
+                return Err(DatabaseError::migration(format!(
+                    "migration {} ({}) has no down script and cannot be rolled back",
+                    migration.version, migration.name
+                )));
+            }
+
+            let mut tx = pool.begin().await?;
+
+            for statement in migration.down {
+                sqlx::query(statement).execute(&mut *tx).await?;
+            }
+
+            sqlx::query("DELETE FROM migrations WHERE version = ?")
+                .bind(migration.version)
+                .execute(&mut *tx)
+                .await?;
+
+            tx.commit().await?;
+            applied.remove(&migration.version);
+        }
+    }
+
+    Ok(())
+}
+
+/// Rolls the schema back by `steps` applied migrations (newest first), using
+/// each migration's `down` script. A no-op if `steps` is `0` or negative; if
+/// the schema has fewer than `steps` migrations applied, it rolls back to
+/// version `0`.
+pub async fn rollback(pool: &SqlitePool, steps: i64) -> Result<(), DatabaseError> {
+    if steps <= 0 {
+        return Ok(());
+    }
+
+    let current_version = schema_version(pool).await?;
+
+    let mut applied_versions: Vec<i64> = MIGRATIONS.iter().map(|m| m.version).filter(|v| *v <= current_version).collect();
+    applied_versions.sort_unstable();
+
+    let keep = applied_versions.len().saturating_sub(steps as usize);
+    let target_version = if keep == 0 { 0 } else { applied_versions[keep - 1] };
+
+    migrate_to(pool, target_version).await
+}
@@ -0,0 +1,124 @@
+//! Postgres-dialect schema for the family/genus/species hierarchy.
+//!
+//! This backs [`crate::database::PgBotanicalDatabase`], the Postgres
+//! counterpart of [`crate::database::BotanicalDatabase`] gated behind the
+//! `postgres` feature. It intentionally mirrors only the tables of the first
+//! three SQLite migrations in [`super::MIGRATIONS`] - the slice of the schema
+//! [`crate::queries::species_pg`] needs - plus a trigger-maintained
+//! `tsvector` column that stands in for SQLite's FTS5 table for
+//! [`crate::queries::search_pg`], rather than the full checksum-tracked
+//! history; `families`/`genera`/`species` stay client-generated `TEXT` UUID
+//! primary keys on both backends so the species CRUD queries diverge only in
+//! bind-placeholder syntax (`$1` instead of `?`) for now.
+//!
+//! Widening this to the rest of [`super::MIGRATIONS`] (and to the same
+//! versioned, checksum-tracked runner SQLite uses) is follow-up work once
+//! more of `queries::*` grows a Postgres counterpart.
+
+use sqlx::PgPool;
+
+use crate::error::DatabaseError;
+
+const POSTGRES_SCHEMA: &[&str] = &[
+    r#"
+    CREATE TABLE IF NOT EXISTS families (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        authority TEXT
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS genera (
+        id TEXT PRIMARY KEY,
+        family_id TEXT NOT NULL REFERENCES families(id),
+        name TEXT NOT NULL,
+        authority TEXT
+    )
+    "#,
+    r#"
+    CREATE TABLE IF NOT EXISTS species (
+        id TEXT PRIMARY KEY,
+        genus_id TEXT NOT NULL REFERENCES genera(id),
+        specific_epithet TEXT NOT NULL,
+        authority TEXT,
+        publication_year INTEGER,
+        conservation_status TEXT
+    )
+    "#,
+    // Backs [`crate::queries::search_pg::search_species`]. There is no
+    // FTS5-style shadow table on this backend, so the index lives directly
+    // on `species` as a `tsvector` column kept in sync by the trigger below
+    // rather than by a generated column, since it needs to read the parent
+    // genus/family names too.
+    "ALTER TABLE species ADD COLUMN IF NOT EXISTS search_vector tsvector",
+    r#"
+    CREATE OR REPLACE FUNCTION species_search_vector_update() RETURNS trigger AS $$
+    BEGIN
+        SELECT setweight(to_tsvector('simple', coalesce(NEW.specific_epithet, '')), 'A') ||
+               setweight(to_tsvector('simple', coalesce(NEW.authority, '')), 'B') ||
+               setweight(to_tsvector('simple', coalesce(g.name, '')), 'A') ||
+               setweight(to_tsvector('simple', coalesce(f.name, '')), 'B')
+          INTO NEW.search_vector
+          FROM genera g JOIN families f ON f.id = g.family_id
+          WHERE g.id = NEW.genus_id;
+        RETURN NEW;
+    END;
+    $$ LANGUAGE plpgsql
+    "#,
+    r#"
+    DROP TRIGGER IF EXISTS species_search_vector_trigger ON species
+    "#,
+    r#"
+    CREATE TRIGGER species_search_vector_trigger
+    BEFORE INSERT OR UPDATE OF specific_epithet, authority, genus_id ON species
+    FOR EACH ROW EXECUTE FUNCTION species_search_vector_update()
+    "#,
+    // Renaming a genus or family doesn't fire the trigger above (it's keyed
+    // off `species`' own columns), so dependent rows are refreshed here by
+    // touching `specific_epithet` to itself, which re-triggers it.
+    r#"
+    CREATE OR REPLACE FUNCTION refresh_species_search_vector_for_genus() RETURNS trigger AS $$
+    BEGIN
+        UPDATE species SET specific_epithet = specific_epithet WHERE genus_id = NEW.id;
+        RETURN NEW;
+    END;
+    $$ LANGUAGE plpgsql
+    "#,
+    r#"
+    DROP TRIGGER IF EXISTS genera_search_vector_trigger ON genera
+    "#,
+    r#"
+    CREATE TRIGGER genera_search_vector_trigger
+    AFTER UPDATE OF name ON genera
+    FOR EACH ROW EXECUTE FUNCTION refresh_species_search_vector_for_genus()
+    "#,
+    r#"
+    CREATE OR REPLACE FUNCTION refresh_species_search_vector_for_family() RETURNS trigger AS $$
+    BEGIN
+        UPDATE species s SET specific_epithet = s.specific_epithet
+          FROM genera g
+          WHERE g.id = s.genus_id AND g.family_id = NEW.id;
+        RETURN NEW;
+    END;
+    $$ LANGUAGE plpgsql
+    "#,
+    r#"
+    DROP TRIGGER IF EXISTS families_search_vector_trigger ON families
+    "#,
+    r#"
+    CREATE TRIGGER families_search_vector_trigger
+    AFTER UPDATE OF name ON families
+    FOR EACH ROW EXECUTE FUNCTION refresh_species_search_vector_for_family()
+    "#,
+];
+
+/// Creates the `families`/`genera`/`species` tables (plus the `search_vector`
+/// trigger machinery backing [`crate::queries::search_pg`]) if they do not
+/// already exist.
+pub async fn run_postgres_migrations(pool: &PgPool) -> Result<(), DatabaseError> {
+    for statement in POSTGRES_SCHEMA {
+        sqlx::query(statement).execute(pool).await?;
+    }
+
+    Ok(())
+}
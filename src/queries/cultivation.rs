@@ -0,0 +1,585 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{Acquire, Sqlite, SqlitePool, Row};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+use crate::types::{CultivationJournalEntry, CultivationRecord, Environment, GrowthStage, JournalEntryKind};
+
+/// Insert a new environment reading using an existing connection or open transaction.
+pub async fn insert_environment_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    environment: &Environment,
+) -> Result<(), DatabaseError> {
+    let mut conn = conn.acquire().await?;
+
+    sqlx::query(
+        "INSERT INTO environments (id, temperature_celsius, humidity_percent, ph_level, light_hours, co2_ppm, recorded_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(environment.id.to_string())
+    .bind(environment.temperature_celsius)
+    .bind(environment.humidity_percent)
+    .bind(environment.ph_level)
+    .bind(environment.light_hours)
+    .bind(environment.co2_ppm)
+    .bind(environment.recorded_at.to_rfc3339())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Insert a new environment reading into the database
+pub async fn insert_environment(pool: &SqlitePool, environment: &Environment) -> Result<(), DatabaseError> {
+    let mut tx = pool.begin().await?;
+    insert_environment_tx(&mut tx, environment).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Get an environment reading by ID
+pub async fn get_environment_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Environment>, DatabaseError> {
+    let row = sqlx::query(
+        "SELECT id, temperature_celsius, humidity_percent, ph_level, light_hours, co2_ppm, recorded_at \
+         FROM environments WHERE id = ?",
+    )
+    .bind(id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|row| row_to_environment(&row)).transpose()
+}
+
+/// Update an environment reading
+pub async fn update_environment(pool: &SqlitePool, id: Uuid, environment: &Environment) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(
+        "UPDATE environments SET temperature_celsius = ?, humidity_percent = ?, ph_level = ?, light_hours = ?, co2_ppm = ?, recorded_at = ? \
+         WHERE id = ?",
+    )
+    .bind(environment.temperature_celsius)
+    .bind(environment.humidity_percent)
+    .bind(environment.ph_level)
+    .bind(environment.light_hours)
+    .bind(environment.co2_ppm)
+    .bind(environment.recorded_at.to_rfc3339())
+    .bind(id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete an environment reading
+pub async fn delete_environment(pool: &SqlitePool, id: Uuid) -> Result<bool, DatabaseError> {
+    let result = sqlx::query("DELETE FROM environments WHERE id = ?")
+        .bind(id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+fn row_to_environment(row: &sqlx::sqlite::SqliteRow) -> Result<Environment, DatabaseError> {
+    let id_str: String = row.get("id");
+    let recorded_at_str: String = row.get("recorded_at");
+
+    let recorded_at = DateTime::parse_from_rfc3339(&recorded_at_str)
+        .map_err(|e| DatabaseError::validation(e.to_string()))?
+        .with_timezone(&Utc);
+
+    Ok(Environment {
+        id: Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        temperature_celsius: row.get("temperature_celsius"),
+        humidity_percent: row.get("humidity_percent"),
+        ph_level: row.get("ph_level"),
+        light_hours: row.get("light_hours"),
+        co2_ppm: row.get("co2_ppm"),
+        recorded_at,
+    })
+}
+
+/// Insert a new cultivation record using an existing connection or open transaction.
+pub async fn insert_cultivation_record_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    record: &CultivationRecord,
+) -> Result<(), DatabaseError> {
+    record.validate()?;
+
+    let mut conn = conn.acquire().await?;
+
+    let photos_json =
+        serde_json::to_string(&record.photos).map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    sqlx::query(
+        "INSERT INTO cultivation_records (id, species_id, growth_stage, environment_id, notes, photos, recorded_at, cultivator) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(record.id.to_string())
+    .bind(record.species_id.to_string())
+    .bind(record.growth_stage.as_str())
+    .bind(record.environment_id.map(|id| id.to_string()))
+    .bind(&record.notes)
+    .bind(photos_json)
+    .bind(record.recorded_at.to_rfc3339())
+    .bind(&record.cultivator)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Insert a new cultivation record into the database
+pub async fn insert_cultivation_record(pool: &SqlitePool, record: &CultivationRecord) -> Result<(), DatabaseError> {
+    let mut tx = pool.begin().await?;
+    insert_cultivation_record_tx(&mut tx, record).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Get a cultivation record by ID
+pub async fn get_cultivation_record_by_id(
+    pool: &SqlitePool,
+    id: Uuid,
+) -> Result<Option<CultivationRecord>, DatabaseError> {
+    let row = sqlx::query(
+        "SELECT id, species_id, growth_stage, environment_id, notes, photos, recorded_at, cultivator \
+         FROM cultivation_records WHERE id = ?",
+    )
+    .bind(id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    row.map(|row| row_to_cultivation_record(&row)).transpose()
+}
+
+/// Get all cultivation records for a species, oldest first
+pub async fn get_cultivation_records_by_species_id(
+    pool: &SqlitePool,
+    species_id: Uuid,
+) -> Result<Vec<CultivationRecord>, DatabaseError> {
+    let rows = sqlx::query(
+        "SELECT id, species_id, growth_stage, environment_id, notes, photos, recorded_at, cultivator \
+         FROM cultivation_records WHERE species_id = ? ORDER BY recorded_at",
+    )
+    .bind(species_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        records.push(row_to_cultivation_record(&row)?);
+    }
+
+    Ok(records)
+}
+
+/// Update a cultivation record
+pub async fn update_cultivation_record(
+    pool: &SqlitePool,
+    id: Uuid,
+    record: &CultivationRecord,
+) -> Result<bool, DatabaseError> {
+    let photos_json =
+        serde_json::to_string(&record.photos).map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    let result = sqlx::query(
+        "UPDATE cultivation_records SET species_id = ?, growth_stage = ?, environment_id = ?, notes = ?, photos = ?, recorded_at = ?, cultivator = ? \
+         WHERE id = ?",
+    )
+    .bind(record.species_id.to_string())
+    .bind(record.growth_stage.as_str())
+    .bind(record.environment_id.map(|id| id.to_string()))
+    .bind(&record.notes)
+    .bind(photos_json)
+    .bind(record.recorded_at.to_rfc3339())
+    .bind(&record.cultivator)
+    .bind(id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a cultivation record
+pub async fn delete_cultivation_record(pool: &SqlitePool, id: Uuid) -> Result<bool, DatabaseError> {
+    let result = sqlx::query("DELETE FROM cultivation_records WHERE id = ?")
+        .bind(id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Default number of days a timeline page extends on either side of the
+/// reference date, loosely modeled on a one-season viewing window.
+pub const DEFAULT_TIMELINE_OFFSET_DAYS: i64 = 356;
+
+/// A bounded window of cultivation records for a single plant, with the
+/// resolved `[from, to]` bounds so a UI can keep scrolling the window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelinePage<T> {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub records: Vec<T>,
+}
+
+/// Finds all cultivation records for `species_id` whose `recorded_at` falls
+/// within `[relative_to - offset_days, relative_to + offset_days]`.
+///
+/// Date arithmetic is checked and clamps at the chrono min/max bound rather
+/// than panicking if `relative_to` is close to either extreme.
+pub async fn find_cultivation_timeline(
+    pool: &SqlitePool,
+    species_id: Uuid,
+    relative_to: DateTime<Utc>,
+    offset_days: i64,
+) -> Result<TimelinePage<CultivationRecord>, DatabaseError> {
+    let offset = Duration::days(offset_days);
+    let from = relative_to
+        .checked_sub_signed(offset)
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let to = relative_to
+        .checked_add_signed(offset)
+        .unwrap_or(DateTime::<Utc>::MAX_UTC);
+
+    let rows = sqlx::query(
+        "SELECT id, species_id, growth_stage, environment_id, notes, photos, recorded_at, cultivator \
+         FROM cultivation_records \
+         WHERE species_id = ? AND recorded_at BETWEEN ? AND ? \
+         ORDER BY recorded_at",
+    )
+    .bind(species_id.to_string())
+    .bind(from.to_rfc3339())
+    .bind(to.to_rfc3339())
+    .fetch_all(pool)
+    .await?;
+
+    let mut records = Vec::with_capacity(rows.len());
+    for row in rows {
+        records.push(row_to_cultivation_record(&row)?);
+    }
+
+    Ok(TimelinePage { from, to, records })
+}
+
+pub(crate) fn row_to_cultivation_record(row: &sqlx::sqlite::SqliteRow) -> Result<CultivationRecord, DatabaseError> {
+    let id_str: String = row.get("id");
+    let species_id_str: String = row.get("species_id");
+    let growth_stage_str: String = row.get("growth_stage");
+    let environment_id_str: Option<String> = row.get("environment_id");
+    let notes: Option<String> = row.get("notes");
+    let photos_json: String = row.get("photos");
+    let recorded_at_str: String = row.get("recorded_at");
+    let cultivator: String = row.get("cultivator");
+
+    let growth_stage = GrowthStage::from_str(&growth_stage_str)
+        .ok_or_else(|| DatabaseError::validation(format!("unknown growth stage: {}", growth_stage_str)))?;
+
+    let environment_id = environment_id_str
+        .map(|s| Uuid::parse_str(&s))
+        .transpose()
+        .map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    let photos: Vec<String> =
+        serde_json::from_str(&photos_json).map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    let recorded_at = DateTime::parse_from_rfc3339(&recorded_at_str)
+        .map_err(|e| DatabaseError::validation(e.to_string()))?
+        .with_timezone(&Utc);
+
+    Ok(CultivationRecord {
+        id: Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        species_id: Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        growth_stage,
+        environment_id,
+        notes,
+        photos,
+        recorded_at,
+        cultivator,
+    })
+}
+
+/// 64-bit FNV-1a over `data`, formatted as lowercase hex.
+fn fnv1a_hex(data: &[u8]) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for &byte in data {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    format!("{:016x}", hash)
+}
+
+fn journal_entry_hash(
+    prev_hash: &str,
+    species_id: Uuid,
+    entity_id: Uuid,
+    sequence: i64,
+    recorded_at: DateTime<Utc>,
+) -> String {
+    let payload = format!("{}|{}|{}|{}|{}", prev_hash, species_id, entity_id, sequence, recorded_at.to_rfc3339());
+    fnv1a_hex(payload.as_bytes())
+}
+
+fn row_to_journal_entry(row: &sqlx::sqlite::SqliteRow) -> Result<CultivationJournalEntry, DatabaseError> {
+    let id_str: String = row.get("id");
+    let species_id_str: String = row.get("species_id");
+    let entity_id_str: String = row.get("entity_id");
+    let kind_str: String = row.get("entity_kind");
+    let prev_id_str: Option<String> = row.get("prev_id");
+    let recorded_at_str: String = row.get("recorded_at");
+
+    let prev_id = prev_id_str
+        .map(|s| Uuid::parse_str(&s).map_err(|e| DatabaseError::validation(e.to_string())))
+        .transpose()?;
+
+    Ok(CultivationJournalEntry {
+        id: Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        species_id: Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        entity_id: Uuid::parse_str(&entity_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        kind: JournalEntryKind::from_str(&kind_str)
+            .ok_or_else(|| DatabaseError::validation(format!("unknown journal entry kind: {}", kind_str)))?,
+        sequence: row.get("sequence"),
+        prev_id,
+        prev_hash: row.get("prev_hash"),
+        entry_hash: row.get("entry_hash"),
+        recorded_at: DateTime::parse_from_rfc3339(&recorded_at_str)
+            .map_err(|e| DatabaseError::validation(e.to_string()))?
+            .with_timezone(&Utc),
+    })
+}
+
+/// Appends a tamper-evident journal entry for `entity_id` (a
+/// [`CultivationRecord`] or [`Environment`] row, disambiguated by `kind`) to
+/// `species_id`'s chain, within an existing connection or open transaction.
+///
+/// The chain's current tip is looked up in `cultivation_journal_heads`
+/// (O(1), rather than scanning `cultivation_journal` for the max
+/// `sequence`) and repointed at the new entry once it is inserted. The new
+/// entry's `prev_id`/`prev_hash` link to that tip (`None`/empty string if
+/// this is the species' first entry) and its `entry_hash` covers
+/// `prev_hash` plus the entry's own fields, so [`verify_cultivation_journal`]
+/// can detect a row that was edited or deleted after the fact.
+///
+/// Callers MUST serialize this against concurrent appends for the same
+/// `species_id` (see [`record_cultivation_event`]/[`record_environment_reading`],
+/// which do so via `BEGIN IMMEDIATE`) - reading the tip and inserting the
+/// new entry are two separate statements, and without a write lock held
+/// across both, two concurrent appends could both read the same tip and
+/// fork the chain.
+pub async fn append_cultivation_journal_entry_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    species_id: Uuid,
+    kind: JournalEntryKind,
+    entity_id: Uuid,
+) -> Result<CultivationJournalEntry, DatabaseError> {
+    let mut conn = conn.acquire().await?;
+
+    let head = sqlx::query("SELECT entry_id FROM cultivation_journal_heads WHERE species_id = ?")
+        .bind(species_id.to_string())
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    let (sequence, prev_id, prev_hash) = match head {
+        Some(row) => {
+            let prev_id_str: String = row.get("entry_id");
+            let tip = sqlx::query("SELECT sequence, entry_hash FROM cultivation_journal WHERE id = ?")
+                .bind(&prev_id_str)
+                .fetch_one(&mut *conn)
+                .await?;
+            let prev_id = Uuid::parse_str(&prev_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?;
+            (tip.get::<i64, _>("sequence") + 1, Some(prev_id), tip.get::<String, _>("entry_hash"))
+        }
+        None => (0, None, String::new()),
+    };
+
+    let recorded_at = Utc::now();
+    let entry_hash = journal_entry_hash(&prev_hash, species_id, entity_id, sequence, recorded_at);
+    let entry = CultivationJournalEntry {
+        id: Uuid::new_v4(),
+        species_id,
+        entity_id,
+        kind,
+        sequence,
+        prev_id,
+        prev_hash,
+        entry_hash,
+        recorded_at,
+    };
+
+    sqlx::query(
+        "INSERT INTO cultivation_journal (id, species_id, entity_id, entity_kind, sequence, prev_id, prev_hash, entry_hash, recorded_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(entry.id.to_string())
+    .bind(entry.species_id.to_string())
+    .bind(entry.entity_id.to_string())
+    .bind(entry.kind.as_str())
+    .bind(entry.sequence)
+    .bind(entry.prev_id.map(|id| id.to_string()))
+    .bind(&entry.prev_hash)
+    .bind(&entry.entry_hash)
+    .bind(entry.recorded_at.to_rfc3339())
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO cultivation_journal_heads (species_id, entry_id) VALUES (?, ?) \
+         ON CONFLICT(species_id) DO UPDATE SET entry_id = excluded.entry_id",
+    )
+    .bind(entry.species_id.to_string())
+    .bind(entry.id.to_string())
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Inserts `record` and appends its journal entry in one transaction, so the
+/// cultivation record and its tamper-evident chain entry are never out of
+/// sync with each other.
+///
+/// The transaction is started with `BEGIN IMMEDIATE` rather than
+/// [`SqlitePool::begin`]'s default deferred transaction, so the write lock
+/// is acquired up front instead of lazily on the first write: a concurrent
+/// `record_cultivation_event`/[`record_environment_reading`] call for any
+/// species blocks here until this one commits, rather than both reading the
+/// same `cultivation_journal_heads` tip and forking the chain (the
+/// `UNIQUE(species_id, sequence)` constraint would only turn that race into
+/// an error for one of the two writers, not prevent it).
+pub async fn record_cultivation_event(
+    pool: &SqlitePool,
+    record: &CultivationRecord,
+) -> Result<CultivationJournalEntry, DatabaseError> {
+    let mut conn = pool.acquire().await?;
+    sqlx::query("BEGIN IMMEDIATE").execute(&mut *conn).await?;
+
+    let result = match insert_cultivation_record_tx(&mut conn, record).await {
+        Ok(()) => {
+            append_cultivation_journal_entry_tx(&mut conn, record.species_id, JournalEntryKind::CultivationRecord, record.id)
+                .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(entry) => {
+            sqlx::query("COMMIT").execute(&mut *conn).await?;
+            Ok(entry)
+        }
+        Err(e) => {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            Err(e)
+        }
+    }
+}
+
+/// Inserts `environment` and appends its journal entry for `species_id` in
+/// one transaction, same as [`record_cultivation_event`] but for
+/// [`Environment`] readings, which previously were never journaled at all.
+pub async fn record_environment_reading(
+    pool: &SqlitePool,
+    species_id: Uuid,
+    environment: &Environment,
+) -> Result<CultivationJournalEntry, DatabaseError> {
+    let mut conn = pool.acquire().await?;
+    sqlx::query("BEGIN IMMEDIATE").execute(&mut *conn).await?;
+
+    let result = match insert_environment_tx(&mut conn, environment).await {
+        Ok(()) => {
+            append_cultivation_journal_entry_tx(&mut conn, species_id, JournalEntryKind::EnvironmentReading, environment.id)
+                .await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(entry) => {
+            sqlx::query("COMMIT").execute(&mut *conn).await?;
+            Ok(entry)
+        }
+        Err(e) => {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            Err(e)
+        }
+    }
+}
+
+/// Fetches the full journal chain for `species_id`, ordered by `sequence`
+/// (oldest first).
+pub async fn get_cultivation_journal(
+    pool: &SqlitePool,
+    species_id: Uuid,
+) -> Result<Vec<CultivationJournalEntry>, DatabaseError> {
+    let rows = sqlx::query(
+        "SELECT id, species_id, entity_id, entity_kind, sequence, prev_id, prev_hash, entry_hash, recorded_at \
+         FROM cultivation_journal WHERE species_id = ? ORDER BY sequence",
+    )
+    .bind(species_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    rows.iter().map(row_to_journal_entry).collect()
+}
+
+/// Walks `species_id`'s journal chain newest-to-oldest, starting from its
+/// `cultivation_journal_heads` entry and following `prev_id` rather than
+/// relying on `sequence` order - the traversal the `heads`/`prev_id` pointer
+/// chain was built for. Returns an empty `Vec` if the species has no entries.
+pub async fn iterate_cultivation_journal(
+    pool: &SqlitePool,
+    species_id: Uuid,
+) -> Result<Vec<CultivationJournalEntry>, DatabaseError> {
+    let head = sqlx::query("SELECT entry_id FROM cultivation_journal_heads WHERE species_id = ?")
+        .bind(species_id.to_string())
+        .fetch_optional(pool)
+        .await?;
+
+    let Some(head) = head else { return Ok(Vec::new()) };
+
+    let mut next_id: Option<String> = Some(head.get("entry_id"));
+    let mut entries = Vec::new();
+
+    while let Some(id) = next_id {
+        let row = sqlx::query(
+            "SELECT id, species_id, entity_id, entity_kind, sequence, prev_id, prev_hash, entry_hash, recorded_at \
+             FROM cultivation_journal WHERE id = ?",
+        )
+        .bind(&id)
+        .fetch_one(pool)
+        .await?;
+
+        let entry = row_to_journal_entry(&row)?;
+        next_id = entry.prev_id.map(|id| id.to_string());
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
+/// Recomputes `species_id`'s journal chain from its first entry and compares
+/// each recomputed hash against what is stored, returning `false` at the
+/// first mismatch (a row edited, deleted, or reordered after the fact) and
+/// `true` if every entry still checks out.
+pub async fn verify_cultivation_journal(pool: &SqlitePool, species_id: Uuid) -> Result<bool, DatabaseError> {
+    let entries = get_cultivation_journal(pool, species_id).await?;
+
+    let mut expected_prev_hash = String::new();
+    for entry in &entries {
+        if entry.prev_hash != expected_prev_hash {
+            return Ok(false);
+        }
+
+        let expected_hash =
+            journal_entry_hash(&entry.prev_hash, entry.species_id, entry.entity_id, entry.sequence, entry.recorded_at);
+        if expected_hash != entry.entry_hash {
+            return Ok(false);
+        }
+
+        expected_prev_hash = entry.entry_hash.clone();
+    }
+
+    Ok(true)
+}
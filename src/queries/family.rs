@@ -1,32 +1,139 @@
-use sqlx::{SqlitePool, Row};
+use std::collections::HashMap;
+
+use sqlx::{Acquire, Sqlite, SqlitePool, Row};
 use uuid::Uuid;
 use crate::error::DatabaseError;
-use crate::types::Family;
+use crate::query_builder::{FamiliesCol, SelectBuilder};
+use crate::types::{Family, Genus, Species};
+
+/// Finds a `slug` derived from `name` that isn't already used by another
+/// family, appending `-2`, `-3`, ... to the base slug on collision.
+async fn unique_family_slug(conn: &mut sqlx::SqliteConnection, name: &str) -> Result<String, DatabaseError> {
+    let base_slug = crate::slug::slugify(name);
+    let mut slug = base_slug.clone();
+    let mut suffix = 2;
+
+    loop {
+        let exists = sqlx::query("SELECT 1 FROM families WHERE slug = ?")
+            .bind(&slug)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some();
+
+        if !exists {
+            return Ok(slug);
+        }
+
+        slug = format!("{}-{}", base_slug, suffix);
+        suffix += 1;
+    }
+}
+
+/// Insert a new family using an existing connection or open transaction.
+///
+/// `slug` is generated from `name` and disambiguated against existing
+/// families automatically; it is not read from `family` (the struct has no
+/// `slug` field - see [`get_family_by_slug`]). The slug's uniqueness is only
+/// enforced by the existence check in [`unique_family_slug`] followed by
+/// this insert, which are not atomic, so two concurrent inserts of
+/// same-named families can both pick the same free slug; `idx_families_slug`
+/// is a UNIQUE index (migration `enforce_unique_slugs`) so the loser of that
+/// race gets a `ConstraintViolation` here rather than a silent duplicate,
+/// and is retried with a freshly-recomputed slug up to
+/// [`crate::slug::MAX_SLUG_INSERT_ATTEMPTS`] times.
+pub async fn insert_family_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    family: &Family,
+) -> Result<(), DatabaseError> {
+    family.validate()?;
+
+    let mut conn = conn.acquire().await?;
+
+    for attempt in 1..=crate::slug::MAX_SLUG_INSERT_ATTEMPTS {
+        let slug = unique_family_slug(&mut *conn, &family.name).await?;
+
+        let result = sqlx::query("INSERT INTO families (id, name, authority, slug) VALUES (?, ?, ?, ?)")
+            .bind(family.id.to_string())
+            .bind(&family.name)
+            .bind(&family.authority)
+            .bind(&slug)
+            .execute(&mut *conn)
+            .await;
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                let err = DatabaseError::from(e);
+                let is_last_attempt = attempt == crate::slug::MAX_SLUG_INSERT_ATTEMPTS;
+                if is_last_attempt || !matches!(err, DatabaseError::ConstraintViolation(_)) {
+                    return Err(err);
+                }
+                // Otherwise a concurrent insert won the same slug first;
+                // loop around to recompute it against the now-committed row.
+            }
+        }
+    }
+
+    unreachable!("loop always returns on its last attempt")
+}
 
 /// Insert a new family into the database
 pub async fn insert_family(pool: &SqlitePool, family: &Family) -> Result<(), DatabaseError> {
-    sqlx::query("INSERT INTO families (id, name, authority) VALUES (?, ?, ?)")
-        .bind(family.id.to_string())
-        .bind(&family.name)
-        .bind(&family.authority)
-        .execute(pool)
-        .await?;
-    
+    let mut tx = pool.begin().await?;
+    insert_family_tx(&mut tx, family).await?;
+    tx.commit().await?;
     Ok(())
 }
 
 /// Get a family by ID
 pub async fn get_family_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Family>, DatabaseError> {
-    let row = sqlx::query("SELECT id, name, authority FROM families WHERE id = ?")
+    let sql = SelectBuilder::new("families")
+        .column(FamiliesCol::Id)
+        .column(FamiliesCol::Name)
+        .column(FamiliesCol::Authority)
+        .and_where_eq(FamiliesCol::Id)
+        .build();
+
+    let row = sqlx::query(&sql)
         .bind(id.to_string())
         .fetch_optional(pool)
         .await?;
-    
+
+    if let Some(row) = row {
+        let id_str: String = row.get("id");
+        let name: String = row.get("name");
+        let authority: String = row.get("authority");
+
+        Ok(Some(Family::with_id(
+            Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            name,
+            authority,
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Get a family by its slug (e.g. `rosaceae`), the stable URL-friendly
+/// identifier assigned at insert time.
+pub async fn get_family_by_slug(pool: &SqlitePool, slug: &str) -> Result<Option<Family>, DatabaseError> {
+    let sql = SelectBuilder::new("families")
+        .column(FamiliesCol::Id)
+        .column(FamiliesCol::Name)
+        .column(FamiliesCol::Authority)
+        .and_where_eq(FamiliesCol::Slug)
+        .build();
+
+    let row = sqlx::query(&sql)
+        .bind(slug)
+        .fetch_optional(pool)
+        .await?;
+
     if let Some(row) = row {
         let id_str: String = row.get("id");
         let name: String = row.get("name");
         let authority: String = row.get("authority");
-        
+
         Ok(Some(Family::with_id(
             Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
             name,
@@ -39,45 +146,198 @@ pub async fn get_family_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Fami
 
 /// Get families by name pattern
 pub async fn get_families_by_name(pool: &SqlitePool, name: &str) -> Result<Vec<Family>, DatabaseError> {
-    let rows = sqlx::query("SELECT id, name, authority FROM families WHERE name LIKE ? ORDER BY name")
+    let sql = SelectBuilder::new("families")
+        .column(FamiliesCol::Id)
+        .column(FamiliesCol::Name)
+        .column(FamiliesCol::Authority)
+        .and_where_like(FamiliesCol::Name)
+        .order_by(FamiliesCol::Name)
+        .build();
+
+    let rows = sqlx::query(&sql)
         .bind(format!("%{}%", name))
         .fetch_all(pool)
         .await?;
-    
+
     let mut families = Vec::new();
     for row in rows {
         let id_str: String = row.get("id");
         let name: String = row.get("name");
         let authority: String = row.get("authority");
-        
+
         families.push(Family::with_id(
             Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
             name,
             authority,
         ));
     }
-    
+
     Ok(families)
 }
 
-/// Update a family
-pub async fn update_family(pool: &SqlitePool, id: Uuid, family: &Family) -> Result<bool, DatabaseError> {
+/// Update a family using an existing connection or open transaction.
+pub async fn update_family_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    id: Uuid,
+    family: &Family,
+) -> Result<bool, DatabaseError> {
+    let mut conn = conn.acquire().await?;
+
     let result = sqlx::query("UPDATE families SET name = ?, authority = ? WHERE id = ?")
         .bind(&family.name)
         .bind(&family.authority)
         .bind(id.to_string())
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
-    
+
     Ok(result.rows_affected() > 0)
 }
 
-/// Delete a family
-pub async fn delete_family(pool: &SqlitePool, id: Uuid) -> Result<bool, DatabaseError> {
+/// Update a family
+pub async fn update_family(pool: &SqlitePool, id: Uuid, family: &Family) -> Result<bool, DatabaseError> {
+    let mut tx = pool.begin().await?;
+    let updated = update_family_tx(&mut tx, id, family).await?;
+    tx.commit().await?;
+    Ok(updated)
+}
+
+/// Regenerates `id`'s slug from its current `name`, disambiguating against
+/// other families the same way [`insert_family_tx`] does. `update_family`
+/// leaves the slug untouched on a rename so external links stay valid; call
+/// this explicitly when a rename should also update the slug.
+pub async fn regenerate_family_slug(pool: &SqlitePool, id: Uuid) -> Result<String, DatabaseError> {
+    let family = get_family_by_id(pool, id)
+        .await?
+        .ok_or_else(|| DatabaseError::not_found(format!("family {} not found", id)))?;
+
+    let mut tx = pool.begin().await?;
+
+    // Clear the row's own slug first so the uniqueness check only sees
+    // other families' slugs.
+    sqlx::query("UPDATE families SET slug = NULL WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+    let slug = unique_family_slug(&mut *tx, &family.name).await?;
+
+    sqlx::query("UPDATE families SET slug = ? WHERE id = ?")
+        .bind(&slug)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(slug)
+}
+
+/// Delete a family using an existing connection or open transaction.
+pub async fn delete_family_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    id: Uuid,
+) -> Result<bool, DatabaseError> {
+    let mut conn = conn.acquire().await?;
+
     let result = sqlx::query("DELETE FROM families WHERE id = ?")
         .bind(id.to_string())
-        .execute(pool)
+        .execute(&mut *conn)
         .await?;
-    
+
     Ok(result.rows_affected() > 0)
-}
\ No newline at end of file
+}
+
+/// Delete a family
+pub async fn delete_family(pool: &SqlitePool, id: Uuid) -> Result<bool, DatabaseError> {
+    let mut tx = pool.begin().await?;
+    let deleted = delete_family_tx(&mut tx, id).await?;
+    tx.commit().await?;
+    Ok(deleted)
+}
+
+/// One genus and its species within a [`FamilyTree`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenusNode {
+    pub genus: Genus,
+    pub species: Vec<Species>,
+}
+
+/// A family with its full descendant hierarchy, fetched in one round trip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FamilyTree {
+    pub family: Family,
+    pub genera: Vec<GenusNode>,
+}
+
+/// Fetches a family along with every genus and species beneath it in a
+/// single `JOIN`-based query, then groups the flat result rows into the
+/// nested `FamilyTree` structure in Rust (keyed by `genus_id`), preserving
+/// name ordering. Returns `None` if the family does not exist.
+pub async fn get_family_tree(pool: &SqlitePool, family_id: Uuid) -> Result<Option<FamilyTree>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            f.id AS family_id, f.name AS family_name, f.authority AS family_authority,
+            g.id AS genus_id, g.name AS genus_name, g.authority AS genus_authority,
+            s.id AS species_id, s.specific_epithet AS species_epithet, s.authority AS species_authority,
+            s.publication_year AS species_publication_year, s.conservation_status AS species_conservation_status
+        FROM families f
+        LEFT JOIN genera g ON g.family_id = f.id
+        LEFT JOIN species s ON s.genus_id = g.id
+        WHERE f.id = ?
+        ORDER BY g.name, s.specific_epithet
+        "#,
+    )
+    .bind(family_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    if rows.is_empty() {
+        return Ok(None);
+    }
+
+    let family_id_str: String = rows[0].get("family_id");
+    let family = Family::with_id(
+        Uuid::parse_str(&family_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        rows[0].get("family_name"),
+        rows[0].get("family_authority"),
+    );
+
+    let mut genus_order: Vec<Uuid> = Vec::new();
+    let mut genus_nodes: HashMap<Uuid, GenusNode> = HashMap::new();
+
+    for row in &rows {
+        let genus_id_str: Option<String> = row.get("genus_id");
+        let Some(genus_id_str) = genus_id_str else {
+            continue;
+        };
+        let genus_id = Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+        genus_nodes.entry(genus_id).or_insert_with(|| {
+            genus_order.push(genus_id);
+            GenusNode {
+                genus: Genus::with_id(genus_id, family.id, row.get("genus_name"), row.get("genus_authority")),
+                species: Vec::new(),
+            }
+        });
+
+        let species_id_str: Option<String> = row.get("species_id");
+        if let Some(species_id_str) = species_id_str {
+            let species_id = Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?;
+            let node = genus_nodes.get_mut(&genus_id).expect("genus node inserted above");
+            node.species.push(Species::with_id(
+                species_id,
+                genus_id,
+                row.get("species_epithet"),
+                row.get("species_authority"),
+                row.get("species_publication_year"),
+                row.get("species_conservation_status"),
+            ));
+        }
+    }
+
+    let genera = genus_order
+        .into_iter()
+        .map(|id| genus_nodes.remove(&id).expect("genus node recorded in genus_order"))
+        .collect();
+
+    Ok(Some(FamilyTree { family, genera }))
+}
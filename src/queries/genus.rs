@@ -1,18 +1,97 @@
-use sqlx::{SqlitePool, Row};
+use sqlx::{Acquire, Sqlite, SqlitePool, QueryBuilder, Row};
 use uuid::Uuid;
 use crate::error::DatabaseError;
 use crate::types::Genus;
 
+fn row_to_genus(row: &sqlx::sqlite::SqliteRow) -> Result<Genus, DatabaseError> {
+    let id_str: String = row.get("id");
+    let family_id_str: String = row.get("family_id");
+
+    Ok(Genus::with_id(
+        Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        Uuid::parse_str(&family_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        row.get("name"),
+        row.get("authority"),
+    ))
+}
+
+/// Finds a `slug` derived from `name` that isn't already used by another
+/// genus, appending `-2`, `-3`, ... to the base slug on collision.
+async fn unique_genus_slug(conn: &mut sqlx::SqliteConnection, name: &str) -> Result<String, DatabaseError> {
+    let base_slug = crate::slug::slugify(name);
+    let mut slug = base_slug.clone();
+    let mut suffix = 2;
+
+    loop {
+        let exists = sqlx::query("SELECT 1 FROM genera WHERE slug = ?")
+            .bind(&slug)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some();
+
+        if !exists {
+            return Ok(slug);
+        }
+
+        slug = format!("{}-{}", base_slug, suffix);
+        suffix += 1;
+    }
+}
+
+/// Insert a new genus using an existing connection or open transaction.
+///
+/// `slug` is generated from `name` and disambiguated against existing
+/// genera automatically; it is not read from `genus` (the struct has no
+/// `slug` field - see [`get_genus_by_slug`]). The slug's uniqueness is only
+/// enforced by the existence check in [`unique_genus_slug`] followed by this
+/// insert, which are not atomic, so two concurrent inserts of same-named
+/// genera can both pick the same free slug; `idx_genera_slug` is a UNIQUE
+/// index (migration `enforce_unique_slugs`) so the loser of that race gets a
+/// `ConstraintViolation` here rather than a silent duplicate, and is retried
+/// with a freshly-recomputed slug up to
+/// [`crate::slug::MAX_SLUG_INSERT_ATTEMPTS`] times.
+pub async fn insert_genus_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    genus: &Genus,
+) -> Result<(), DatabaseError> {
+    genus.validate()?;
+
+    let mut conn = conn.acquire().await?;
+
+    for attempt in 1..=crate::slug::MAX_SLUG_INSERT_ATTEMPTS {
+        let slug = unique_genus_slug(&mut *conn, &genus.name).await?;
+
+        let result = sqlx::query("INSERT INTO genera (id, family_id, name, authority, slug) VALUES (?, ?, ?, ?, ?)")
+            .bind(genus.id.to_string())
+            .bind(genus.family_id.to_string())
+            .bind(&genus.name)
+            .bind(&genus.authority)
+            .bind(&slug)
+            .execute(&mut *conn)
+            .await;
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                let err = DatabaseError::from(e);
+                let is_last_attempt = attempt == crate::slug::MAX_SLUG_INSERT_ATTEMPTS;
+                if is_last_attempt || !matches!(err, DatabaseError::ConstraintViolation(_)) {
+                    return Err(err);
+                }
+                // Otherwise a concurrent insert won the same slug first;
+                // loop around to recompute it against the now-committed row.
+            }
+        }
+    }
+
+    unreachable!("loop always returns on its last attempt")
+}
+
 /// Insert a new genus into the database
 pub async fn insert_genus(pool: &SqlitePool, genus: &Genus) -> Result<(), DatabaseError> {
-    sqlx::query("INSERT INTO genera (id, family_id, name, authority) VALUES (?, ?, ?, ?)")
-        .bind(genus.id.to_string())
-        .bind(genus.family_id.to_string())
-        .bind(&genus.name)
-        .bind(&genus.authority)
-        .execute(pool)
-        .await?;
-    
+    let mut tx = pool.begin().await?;
+    insert_genus_tx(&mut tx, genus).await?;
+    tx.commit().await?;
     Ok(())
 }
 
@@ -46,14 +125,14 @@ pub async fn get_genera_by_family_id(pool: &SqlitePool, family_id: Uuid) -> Resu
         .bind(family_id.to_string())
         .fetch_all(pool)
         .await?;
-    
+
     let mut genera = Vec::new();
     for row in rows {
         let id_str: String = row.get("id");
         let family_id_str: String = row.get("family_id");
         let name: String = row.get("name");
         let authority: String = row.get("authority");
-        
+
         genera.push(Genus::with_id(
             Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
             Uuid::parse_str(&family_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
@@ -61,10 +140,84 @@ pub async fn get_genera_by_family_id(pool: &SqlitePool, family_id: Uuid) -> Resu
             authority,
         ));
     }
-    
+
     Ok(genera)
 }
 
+/// Get genera for every family ID in `family_ids` in a single query, for
+/// batched callers like [`crate::graphql::GeneraByFamilyLoader`] that would
+/// otherwise issue one [`get_genera_by_family_id`] call per key.
+pub async fn get_genera_by_family_ids(pool: &SqlitePool, family_ids: &[Uuid]) -> Result<Vec<Genus>, DatabaseError> {
+    if family_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut qb: QueryBuilder<Sqlite> =
+        QueryBuilder::new("SELECT id, family_id, name, authority FROM genera WHERE family_id IN (");
+    let mut separated = qb.separated(", ");
+    for family_id in family_ids {
+        separated.push_bind(family_id.to_string());
+    }
+    qb.push(") ORDER BY name");
+
+    let rows = qb.build().fetch_all(pool).await?;
+    rows.iter().map(row_to_genus).collect()
+}
+
+/// Get a genus by its slug (e.g. `rosa`), the stable URL-friendly
+/// identifier assigned at insert time.
+pub async fn get_genus_by_slug(pool: &SqlitePool, slug: &str) -> Result<Option<Genus>, DatabaseError> {
+    let row = sqlx::query("SELECT id, family_id, name, authority FROM genera WHERE slug = ?")
+        .bind(slug)
+        .fetch_optional(pool)
+        .await?;
+
+    if let Some(row) = row {
+        let id_str: String = row.get("id");
+        let family_id_str: String = row.get("family_id");
+        let name: String = row.get("name");
+        let authority: String = row.get("authority");
+
+        Ok(Some(Genus::with_id(
+            Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            Uuid::parse_str(&family_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            name,
+            authority,
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Regenerates `id`'s slug from its current `name`, disambiguating against
+/// other genera the same way [`insert_genus_tx`] does. `update_genus` leaves
+/// the slug untouched on a rename so external links stay valid; call this
+/// explicitly when a rename should also update the slug.
+pub async fn regenerate_genus_slug(pool: &SqlitePool, id: Uuid) -> Result<String, DatabaseError> {
+    let genus = get_genus_by_id(pool, id)
+        .await?
+        .ok_or_else(|| DatabaseError::not_found(format!("genus {} not found", id)))?;
+
+    let mut tx = pool.begin().await?;
+
+    // Clear the row's own slug first so the uniqueness check only sees
+    // other genera's slugs.
+    sqlx::query("UPDATE genera SET slug = NULL WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+    let slug = unique_genus_slug(&mut *tx, &genus.name).await?;
+
+    sqlx::query("UPDATE genera SET slug = ? WHERE id = ?")
+        .bind(&slug)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(slug)
+}
+
 /// Update a genus
 pub async fn update_genus(pool: &SqlitePool, id: Uuid, genus: &Genus) -> Result<bool, DatabaseError> {
     let result = sqlx::query("UPDATE genera SET family_id = ?, name = ?, authority = ? WHERE id = ?")
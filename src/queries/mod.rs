@@ -0,0 +1,13 @@
+pub mod family;
+pub mod genus;
+pub mod species;
+pub mod specimens;
+pub mod search;
+pub mod cultivation;
+pub mod taxa;
+
+#[cfg(feature = "postgres")]
+pub mod species_pg;
+
+#[cfg(feature = "postgres")]
+pub mod search_pg;
@@ -1,7 +1,274 @@
-use sqlx::SqlitePool;
+use std::collections::HashSet;
+
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+use uuid::Uuid;
+
 use crate::error::DatabaseError;
 
-/// Stub implementation for search
-pub async fn search_species(_pool: &SqlitePool, _query: &str) -> Result<Vec<String>, DatabaseError> {
-    Ok(Vec::new())
-}
\ No newline at end of file
+/// The taxonomic rank a [`TaxonHit`] resolved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxonRank {
+    Family,
+    Genus,
+    Species,
+}
+
+impl TaxonRank {
+    fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "family" => Some(TaxonRank::Family),
+            "genus" => Some(TaxonRank::Genus),
+            "species" => Some(TaxonRank::Species),
+            _ => None,
+        }
+    }
+}
+
+/// A single ranked match from [`search_taxonomy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxonHit {
+    pub entity_id: Uuid,
+    pub rank: TaxonRank,
+    pub name: String,
+    pub authority: String,
+    /// BM25 relevance score; lower is more relevant, matching SQLite's `bm25()`.
+    pub score: f32,
+}
+
+/// Searches family/genus/species names and authorities via the `taxonomy_fts`
+/// FTS5 index, ranking results by BM25 relevance. Supports prefix queries
+/// (`rosa*`) and multi-term queries (`rosa linnaeus`) through FTS5's default
+/// query syntax.
+pub async fn search_taxonomy(pool: &SqlitePool, query: &str) -> Result<Vec<TaxonHit>, DatabaseError> {
+    let rows = sqlx::query(
+        "SELECT entity_id, rank, name, authority, bm25(taxonomy_fts) AS score \
+         FROM taxonomy_fts WHERE taxonomy_fts MATCH ? ORDER BY score"
+    )
+    .bind(query)
+    .fetch_all(pool)
+    .await?;
+
+    let mut hits = Vec::with_capacity(rows.len());
+    for row in rows {
+        let entity_id_str: String = row.get("entity_id");
+        let rank_str: String = row.get("rank");
+        let name: String = row.get("name");
+        let authority: String = row.get("authority");
+        let score: f64 = row.get("score");
+
+        hits.push(TaxonHit {
+            entity_id: Uuid::parse_str(&entity_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            rank: TaxonRank::from_str(&rank_str)
+                .ok_or_else(|| DatabaseError::validation(format!("unknown taxon rank: {}", rank_str)))?,
+            name,
+            authority,
+            score: score as f32,
+        });
+    }
+
+    Ok(hits)
+}
+
+/// A single species match from [`search_taxa`], ranked by BM25 relevance
+/// over the denormalized `taxa_fts` index.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxaHit {
+    pub species_id: Uuid,
+    pub epithet: String,
+    pub genus: String,
+    pub family: String,
+    pub authority: String,
+    /// BM25 relevance score; lower is more relevant, matching SQLite's `bm25()`.
+    pub score: f32,
+}
+
+/// Searches species via the `taxa_fts` index, which denormalizes each
+/// species' epithet alongside its genus, family, and authority into one
+/// row so a single `MATCH` can search all four without a join. Supports
+/// FTS5's standard query syntax, including prefix queries (`tri*`) and
+/// column filters (`epithet:alba`, `genus:rosa`), and replaces the
+/// `specific_epithet LIKE '%...%'` scan in
+/// [`crate::queries::species::get_species_by_name`], which can't use an
+/// index because of its leading wildcard. Results are ranked by BM25 and
+/// capped at `limit`.
+pub async fn search_taxa(pool: &SqlitePool, query: &str, limit: u32) -> Result<Vec<TaxaHit>, DatabaseError> {
+    let rows = sqlx::query(
+        "SELECT species_id, epithet, genus, family, authority, bm25(taxa_fts) AS score \
+         FROM taxa_fts WHERE taxa_fts MATCH ? ORDER BY score LIMIT ?"
+    )
+    .bind(query)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    let mut hits = Vec::with_capacity(rows.len());
+    for row in rows {
+        let species_id_str: String = row.get("species_id");
+        let score: f64 = row.get("score");
+
+        hits.push(TaxaHit {
+            species_id: Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            epithet: row.get("epithet"),
+            genus: row.get("genus"),
+            family: row.get("family"),
+            authority: row.get("authority"),
+            score: score as f32,
+        });
+    }
+
+    Ok(hits)
+}
+
+/// Optional predicates [`search_species`] applies on top of its `taxa_fts`
+/// match, joined back against the `species` table the index was
+/// denormalized from.
+#[derive(Debug, Clone, Default)]
+pub struct SpeciesSearchFilters {
+    pub conservation_status: Option<String>,
+    pub genus_id: Option<Uuid>,
+}
+
+/// Full-text searches species names the same way [`search_taxa`] does, then
+/// narrows the matches by `filters` (conservation status, genus) - the
+/// token-matching, filterable replacement for
+/// [`crate::queries::species::get_species_by_name`]'s `LIKE '%x%'` scan,
+/// which both can't use an index and (per `test_get_species_by_name_partial_match`)
+/// returns nearly the whole table for a single-letter query. `query` goes to
+/// FTS5 `MATCH` as is, so terms match whole tokens (or prefixes written with
+/// a trailing `*`); a misspelled term matches nothing. Results are ranked by
+/// BM25 and capped at `limit`.
+pub async fn search_species(
+    pool: &SqlitePool,
+    query: &str,
+    limit: u32,
+    filters: &SpeciesSearchFilters,
+) -> Result<Vec<TaxaHit>, DatabaseError> {
+    let mut qb: QueryBuilder<Sqlite> = QueryBuilder::new(
+        "SELECT m.species_id, m.epithet, m.genus, m.family, m.authority, m.score FROM ( \
+            SELECT species_id, epithet, genus, family, authority, bm25(taxa_fts) AS score \
+            FROM taxa_fts WHERE taxa_fts MATCH ",
+    );
+    qb.push_bind(query.to_string());
+    qb.push(") m JOIN species s ON s.id = m.species_id");
+
+    let mut has_where = false;
+
+    if let Some(status) = &filters.conservation_status {
+        qb.push(" WHERE ");
+        has_where = true;
+        qb.push("s.conservation_status = ").push_bind(status.clone());
+    }
+
+    if let Some(genus_id) = filters.genus_id {
+        qb.push(if has_where { " AND " } else { " WHERE " });
+        qb.push("s.genus_id = ").push_bind(genus_id.to_string());
+    }
+
+    qb.push(" ORDER BY m.score LIMIT ").push_bind(limit as i64);
+
+    let rows = qb.build().fetch_all(pool).await?;
+
+    let mut hits = Vec::with_capacity(rows.len());
+    for row in &rows {
+        let species_id_str: String = row.get("species_id");
+        let score: f64 = row.get("score");
+
+        hits.push(TaxaHit {
+            species_id: Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            epithet: row.get("epithet"),
+            genus: row.get("genus"),
+            family: row.get("family"),
+            authority: row.get("authority"),
+            score: score as f32,
+        });
+    }
+
+    Ok(hits)
+}
+
+/// Default minimum trigram similarity for [`fuzzy_search_taxonomy`] to
+/// consider a name a match; matches `pg_trgm`'s default `similarity()` cutoff.
+pub const DEFAULT_TRIGRAM_THRESHOLD: f32 = 0.3;
+
+/// A single match from [`fuzzy_search_taxonomy`], ranked by trigram similarity.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzyTaxonHit {
+    pub entity_id: Uuid,
+    pub rank: TaxonRank,
+    pub name: String,
+    pub authority: String,
+    /// Jaccard similarity of the query's and name's trigram sets, in `[0.0, 1.0]`.
+    pub similarity: f32,
+}
+
+/// Splits `s` into the set of padded character trigrams used for fuzzy
+/// matching, following `pg_trgm`'s convention of padding the string with
+/// two leading and one trailing space so short names still produce
+/// boundary-sensitive trigrams (e.g. `"ab"` yields `"  a"`, `" ab"`, `"ab "`).
+fn trigrams(s: &str) -> HashSet<String> {
+    let padded: String = format!("  {} ", s.to_lowercase());
+    let chars: Vec<char> = padded.chars().collect();
+
+    if chars.len() < 3 {
+        return HashSet::new();
+    }
+
+    chars.windows(3).map(|w| w.iter().collect()).collect()
+}
+
+/// Jaccard similarity between the trigram sets of `a` and `b`, in `[0.0, 1.0]`.
+fn trigram_similarity(a: &str, b: &str) -> f32 {
+    let a_grams = trigrams(a);
+    let b_grams = trigrams(b);
+
+    if a_grams.is_empty() || b_grams.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = a_grams.intersection(&b_grams).count();
+    let union = a_grams.union(&b_grams).count();
+
+    intersection as f32 / union as f32
+}
+
+/// Fuzzy-matches `query` against every indexed family/genus/species name
+/// using trigram similarity, tolerating typos and minor misspellings that
+/// [`search_taxonomy`]'s FTS5 `MATCH` would miss. SQLite ships no trigram
+/// tokenizer by default, so similarity is computed in Rust over the same
+/// `taxonomy_fts` content table (mirroring how [`crate::contextlite`] scores
+/// embeddings in Rust rather than relying on a database-side extension).
+/// Results are filtered to `similarity >= threshold` and sorted descending.
+pub async fn fuzzy_search_taxonomy(
+    pool: &SqlitePool,
+    query: &str,
+    threshold: f32,
+) -> Result<Vec<FuzzyTaxonHit>, DatabaseError> {
+    let rows = sqlx::query("SELECT entity_id, rank, name, authority FROM taxonomy_fts")
+        .fetch_all(pool)
+        .await?;
+
+    let mut hits = Vec::new();
+    for row in rows {
+        let name: String = row.get("name");
+        let similarity = trigram_similarity(query, &name);
+        if similarity < threshold {
+            continue;
+        }
+
+        let entity_id_str: String = row.get("entity_id");
+        let rank_str: String = row.get("rank");
+
+        hits.push(FuzzyTaxonHit {
+            entity_id: Uuid::parse_str(&entity_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            rank: TaxonRank::from_str(&rank_str)
+                .ok_or_else(|| DatabaseError::validation(format!("unknown taxon rank: {}", rank_str)))?,
+            name,
+            authority: row.get("authority"),
+            similarity,
+        });
+    }
+
+    hits.sort_by(|a, b| b.similarity.partial_cmp(&a.similarity).unwrap_or(std::cmp::Ordering::Equal));
+
+    Ok(hits)
+}
@@ -0,0 +1,75 @@
+//! Postgres-dialect mirror of [`crate::queries::search::search_species`],
+//! for [`crate::database::PgBotanicalDatabase`].
+//!
+//! SQLite's `taxa_fts`/`bm25()` becomes a `tsvector` column plus
+//! `plainto_tsquery`/`ts_rank` here - Postgres has no FTS5-equivalent
+//! virtual table, so the index is an ordinary `tsvector` column on `species`,
+//! kept in sync by triggers, instead of a synchronized shadow table. Note the score polarity flips relative to the
+//! SQLite module: `bm25()` ranks lower as more relevant, while `ts_rank`
+//! ranks higher as more relevant, so callers comparing scores across
+//! backends must not assume a shared scale.
+
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+use crate::queries::search::{SpeciesSearchFilters, TaxaHit};
+
+/// Full-text searches species names via a `tsvector` over epithet, genus,
+/// family, and authority, ranked by `ts_rank` (higher is more relevant) and
+/// narrowed by `filters`. Mirrors [`crate::queries::search::search_species`]
+/// field-for-field so [`TaxaHit`] is shared across both backends.
+pub async fn search_species(
+    pool: &PgPool,
+    query: &str,
+    limit: u32,
+    filters: &SpeciesSearchFilters,
+) -> Result<Vec<TaxaHit>, DatabaseError> {
+    let mut sql = String::from(
+        "SELECT s.id AS species_id, s.specific_epithet AS epithet, g.name AS genus, f.name AS family, \
+         s.authority AS authority, ts_rank(s.search_vector, plainto_tsquery('simple', $1)) AS score \
+         FROM species s \
+         JOIN genera g ON g.id = s.genus_id \
+         JOIN families f ON f.id = g.family_id \
+         WHERE s.search_vector @@ plainto_tsquery('simple', $1)",
+    );
+
+    let mut next_bind = 2;
+    if filters.conservation_status.is_some() {
+        sql.push_str(&format!(" AND s.conservation_status = ${}", next_bind));
+        next_bind += 1;
+    }
+    if filters.genus_id.is_some() {
+        sql.push_str(&format!(" AND s.genus_id = ${}", next_bind));
+        next_bind += 1;
+    }
+    sql.push_str(&format!(" ORDER BY score DESC LIMIT ${}", next_bind));
+
+    let mut q = sqlx::query(&sql).bind(query);
+    if let Some(status) = &filters.conservation_status {
+        q = q.bind(status.clone());
+    }
+    if let Some(genus_id) = filters.genus_id {
+        q = q.bind(genus_id.to_string());
+    }
+    q = q.bind(limit as i64);
+
+    let rows = q.fetch_all(pool).await?;
+
+    let mut hits = Vec::with_capacity(rows.len());
+    for row in &rows {
+        let species_id_str: String = row.get("species_id");
+        let score: f64 = row.get("score");
+
+        hits.push(TaxaHit {
+            species_id: Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            epithet: row.get("epithet"),
+            genus: row.get("genus"),
+            family: row.get("family"),
+            authority: row.get("authority"),
+            score: score as f32,
+        });
+    }
+
+    Ok(hits)
+}
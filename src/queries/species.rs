@@ -1,22 +1,102 @@
-use sqlx::{SqlitePool, Row};
+use sqlx::{Acquire, Sqlite, SqlitePool, QueryBuilder, Row};
 use uuid::Uuid;
 use crate::error::DatabaseError;
 use crate::types::Species;
 
+fn row_to_species(row: &sqlx::sqlite::SqliteRow) -> Result<Species, DatabaseError> {
+    let id_str: String = row.get("id");
+    let genus_id_str: String = row.get("genus_id");
+
+    Ok(Species::with_id(
+        Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        row.get("specific_epithet"),
+        row.get("authority"),
+        row.get("publication_year"),
+        row.get("conservation_status"),
+    ))
+}
+
+/// Finds a `slug` derived from `specific_epithet` that isn't already used by
+/// another species, appending `-2`, `-3`, ... to the base slug on collision.
+async fn unique_species_slug(conn: &mut sqlx::SqliteConnection, specific_epithet: &str) -> Result<String, DatabaseError> {
+    let base_slug = crate::slug::slugify(specific_epithet);
+    let mut slug = base_slug.clone();
+    let mut suffix = 2;
+
+    loop {
+        let exists = sqlx::query("SELECT 1 FROM species WHERE slug = ?")
+            .bind(&slug)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some();
+
+        if !exists {
+            return Ok(slug);
+        }
+
+        slug = format!("{}-{}", base_slug, suffix);
+        suffix += 1;
+    }
+}
+
+/// Insert a new species using an existing connection or open transaction.
+///
+/// `slug` is generated from `specific_epithet` and disambiguated against
+/// existing species automatically; it is not read from `species` (the
+/// struct has no `slug` field - see [`get_species_by_slug`]). The slug's
+/// uniqueness is only enforced by the existence check in
+/// [`unique_species_slug`] followed by this insert, which are not atomic, so
+/// two concurrent inserts of same-epithet species can both pick the same
+/// free slug; `idx_species_slug` is a UNIQUE index (migration
+/// `enforce_unique_slugs`) so the loser of that race gets a
+/// `ConstraintViolation` here rather than a silent duplicate, and is retried
+/// with a freshly-recomputed slug up to
+/// [`crate::slug::MAX_SLUG_INSERT_ATTEMPTS`] times.
+pub async fn insert_species_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    species: &Species,
+) -> Result<(), DatabaseError> {
+    let mut conn = conn.acquire().await?;
+
+    for attempt in 1..=crate::slug::MAX_SLUG_INSERT_ATTEMPTS {
+        let slug = unique_species_slug(&mut *conn, &species.specific_epithet).await?;
+
+        let result = sqlx::query(
+            "INSERT INTO species (id, genus_id, specific_epithet, authority, publication_year, conservation_status, slug) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(species.id.to_string())
+        .bind(species.genus_id.to_string())
+        .bind(&species.specific_epithet)
+        .bind(&species.authority)
+        .bind(species.publication_year)
+        .bind(&species.conservation_status)
+        .bind(&slug)
+        .execute(&mut *conn)
+        .await;
+
+        match result {
+            Ok(_) => return Ok(()),
+            Err(e) => {
+                let err = DatabaseError::from(e);
+                let is_last_attempt = attempt == crate::slug::MAX_SLUG_INSERT_ATTEMPTS;
+                if is_last_attempt || !matches!(err, DatabaseError::ConstraintViolation(_)) {
+                    return Err(err);
+                }
+                // Otherwise a concurrent insert won the same slug first;
+                // loop around to recompute it against the now-committed row.
+            }
+        }
+    }
+
+    unreachable!("loop always returns on its last attempt")
+}
+
 /// Insert a new species into the database
 pub async fn insert_species(pool: &SqlitePool, species: &Species) -> Result<(), DatabaseError> {
-    sqlx::query(
-        "INSERT INTO species (id, genus_id, specific_epithet, authority, publication_year, conservation_status) VALUES (?, ?, ?, ?, ?, ?)"
-    )
-    .bind(species.id.to_string())
-    .bind(species.genus_id.to_string())
-    .bind(&species.specific_epithet)
-    .bind(&species.authority)
-    .bind(species.publication_year)
-    .bind(&species.conservation_status)
-    .execute(pool)
-    .await?;
-    
+    let mut tx = pool.begin().await?;
+    insert_species_tx(&mut tx, species).await?;
+    tx.commit().await?;
     Ok(())
 }
 
@@ -48,6 +128,114 @@ pub async fn get_species_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Spe
     }
 }
 
+/// Get all species belonging to a genus
+pub async fn get_species_by_genus_id(pool: &SqlitePool, genus_id: Uuid) -> Result<Vec<Species>, DatabaseError> {
+    let rows = sqlx::query("SELECT id, genus_id, specific_epithet, authority, publication_year, conservation_status FROM species WHERE genus_id = ? ORDER BY specific_epithet")
+        .bind(genus_id.to_string())
+        .fetch_all(pool)
+        .await?;
+
+    let mut species = Vec::new();
+    for row in rows {
+        let id_str: String = row.get("id");
+        let genus_id_str: String = row.get("genus_id");
+        let specific_epithet: String = row.get("specific_epithet");
+        let authority: String = row.get("authority");
+        let publication_year: Option<i32> = row.get("publication_year");
+        let conservation_status: Option<String> = row.get("conservation_status");
+
+        species.push(Species::with_id(
+            Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            specific_epithet,
+            authority,
+            publication_year,
+            conservation_status,
+        ));
+    }
+
+    Ok(species)
+}
+
+/// Get species for every genus ID in `genus_ids` in a single query, for
+/// batched callers like [`crate::graphql::SpeciesByGenusLoader`] that would
+/// otherwise issue one [`get_species_by_genus_id`] call per key.
+pub async fn get_species_by_genus_ids(pool: &SqlitePool, genus_ids: &[Uuid]) -> Result<Vec<Species>, DatabaseError> {
+    if genus_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut qb: QueryBuilder<Sqlite> = QueryBuilder::new(
+        "SELECT id, genus_id, specific_epithet, authority, publication_year, conservation_status FROM species WHERE genus_id IN (",
+    );
+    let mut separated = qb.separated(", ");
+    for genus_id in genus_ids {
+        separated.push_bind(genus_id.to_string());
+    }
+    qb.push(") ORDER BY specific_epithet");
+
+    let rows = qb.build().fetch_all(pool).await?;
+    rows.iter().map(row_to_species).collect()
+}
+
+/// Get a species by its slug (e.g. `canina`), the stable URL-friendly
+/// identifier assigned at insert time.
+pub async fn get_species_by_slug(pool: &SqlitePool, slug: &str) -> Result<Option<Species>, DatabaseError> {
+    let row = sqlx::query("SELECT id, genus_id, specific_epithet, authority, publication_year, conservation_status FROM species WHERE slug = ?")
+        .bind(slug)
+        .fetch_optional(pool)
+        .await?;
+
+    if let Some(row) = row {
+        let id_str: String = row.get("id");
+        let genus_id_str: String = row.get("genus_id");
+        let specific_epithet: String = row.get("specific_epithet");
+        let authority: String = row.get("authority");
+        let publication_year: Option<i32> = row.get("publication_year");
+        let conservation_status: Option<String> = row.get("conservation_status");
+
+        Ok(Some(Species::with_id(
+            Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            specific_epithet,
+            authority,
+            publication_year,
+            conservation_status,
+        )))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Regenerates `id`'s slug from its current `specific_epithet`, disambiguating
+/// against other species the same way [`insert_species_tx`] does.
+/// `update_species` leaves the slug untouched on a rename so external links
+/// stay valid; call this explicitly when a rename should also update the slug.
+pub async fn regenerate_species_slug(pool: &SqlitePool, id: Uuid) -> Result<String, DatabaseError> {
+    let species = get_species_by_id(pool, id)
+        .await?
+        .ok_or_else(|| DatabaseError::not_found(format!("species {} not found", id)))?;
+
+    let mut tx = pool.begin().await?;
+
+    // Clear the row's own slug first so the uniqueness check only sees
+    // other species' slugs.
+    sqlx::query("UPDATE species SET slug = NULL WHERE id = ?")
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+    let slug = unique_species_slug(&mut *tx, &species.specific_epithet).await?;
+
+    sqlx::query("UPDATE species SET slug = ? WHERE id = ?")
+        .bind(&slug)
+        .bind(id.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(slug)
+}
+
 /// Get species by name pattern
 pub async fn get_species_by_name(pool: &SqlitePool, name: &str) -> Result<Vec<Species>, DatabaseError> {
     let rows = sqlx::query("SELECT id, genus_id, specific_epithet, authority, publication_year, conservation_status FROM species WHERE specific_epithet LIKE ?")
@@ -98,6 +286,177 @@ pub async fn delete_species(pool: &SqlitePool, id: Uuid) -> Result<bool, Databas
         .bind(id.to_string())
         .execute(pool)
         .await?;
-    
+
     Ok(result.rows_affected() > 0)
+}
+
+/// Column to sort [`SpeciesQuery`] results by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpeciesOrderBy {
+    Epithet,
+    PublicationYear,
+}
+
+/// Composable builder for filtered species searches.
+///
+/// Accumulates optional predicates and compiles them into one parameterized
+/// SQL statement (bound arguments only, never string-interpolated values),
+/// so callers can combine filters arbitrarily without a hand-written query
+/// function for every combination.
+#[derive(Debug, Clone, Default)]
+pub struct SpeciesQuery {
+    epithet_like: Option<String>,
+    authority_eq: Option<String>,
+    published_between: Option<(i32, i32)>,
+    conservation_status_in: Vec<String>,
+    family_id: Option<Uuid>,
+    genus_id: Option<Uuid>,
+    order_by: Option<SpeciesOrderBy>,
+    limit: Option<i64>,
+}
+
+impl SpeciesQuery {
+    /// Starts an empty query that matches every species.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches species whose epithet contains `pattern`.
+    pub fn epithet_like(mut self, pattern: &str) -> Self {
+        self.epithet_like = Some(pattern.to_string());
+        self
+    }
+
+    /// Matches species with exactly this taxonomic authority.
+    pub fn authority_eq(mut self, authority: &str) -> Self {
+        self.authority_eq = Some(authority.to_string());
+        self
+    }
+
+    /// Matches species first published within `[min, max]` inclusive.
+    pub fn published_between(mut self, min: i32, max: i32) -> Self {
+        self.published_between = Some((min, max));
+        self
+    }
+
+    /// Matches species whose conservation status is one of `statuses`.
+    pub fn conservation_status_in(mut self, statuses: &[&str]) -> Self {
+        self.conservation_status_in = statuses.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Restricts results to species belonging to the given family.
+    pub fn in_family(mut self, family_id: Uuid) -> Self {
+        self.family_id = Some(family_id);
+        self
+    }
+
+    /// Restricts results to species belonging to the given genus.
+    pub fn in_genus(mut self, genus_id: Uuid) -> Self {
+        self.genus_id = Some(genus_id);
+        self
+    }
+
+    /// Orders results by `column`.
+    pub fn order_by(mut self, column: SpeciesOrderBy) -> Self {
+        self.order_by = Some(column);
+        self
+    }
+
+    /// Caps the number of returned rows.
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Compiles the accumulated predicates into one parameterized statement
+    /// and executes it, returning the matching species.
+    pub async fn execute(&self, pool: &SqlitePool) -> Result<Vec<Species>, DatabaseError> {
+        let mut qb: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "SELECT s.id, s.genus_id, s.specific_epithet, s.authority, s.publication_year, s.conservation_status FROM species s",
+        );
+
+        if self.family_id.is_some() {
+            qb.push(" JOIN genera g ON g.id = s.genus_id");
+        }
+
+        let mut has_where = false;
+
+        if let Some(epithet) = &self.epithet_like {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
+            qb.push("s.specific_epithet LIKE ").push_bind(format!("%{}%", epithet));
+        }
+
+        if let Some(authority) = &self.authority_eq {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
+            qb.push("s.authority = ").push_bind(authority.clone());
+        }
+
+        if let Some((min, max)) = self.published_between {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
+            qb.push("s.publication_year BETWEEN ").push_bind(min).push(" AND ").push_bind(max);
+        }
+
+        if !self.conservation_status_in.is_empty() {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
+            qb.push("s.conservation_status IN (");
+            let mut separated = qb.separated(", ");
+            for status in &self.conservation_status_in {
+                separated.push_bind(status.clone());
+            }
+            qb.push(")");
+        }
+
+        if let Some(genus_id) = self.genus_id {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            has_where = true;
+            qb.push("s.genus_id = ").push_bind(genus_id.to_string());
+        }
+
+        if let Some(family_id) = self.family_id {
+            qb.push(if has_where { " AND " } else { " WHERE " });
+            qb.push("g.family_id = ").push_bind(family_id.to_string());
+        }
+
+        match self.order_by {
+            Some(SpeciesOrderBy::Epithet) => {
+                qb.push(" ORDER BY s.specific_epithet");
+            }
+            Some(SpeciesOrderBy::PublicationYear) => {
+                qb.push(" ORDER BY s.publication_year");
+            }
+            None => {}
+        }
+
+        if let Some(limit) = self.limit {
+            qb.push(" LIMIT ").push_bind(limit);
+        }
+
+        let rows = qb.build().fetch_all(pool).await?;
+
+        let mut species = Vec::with_capacity(rows.len());
+        for row in rows {
+            let id_str: String = row.get("id");
+            let genus_id_str: String = row.get("genus_id");
+            let specific_epithet: String = row.get("specific_epithet");
+            let authority: String = row.get("authority");
+            let publication_year: Option<i32> = row.get("publication_year");
+            let conservation_status: Option<String> = row.get("conservation_status");
+
+            species.push(Species::with_id(
+                Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+                Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+                specific_epithet,
+                authority,
+                publication_year,
+                conservation_status,
+            ));
+        }
+
+        Ok(species)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,89 @@
+//! Postgres-dialect mirror of [`crate::queries::species`], for
+//! [`crate::database::PgBotanicalDatabase`].
+//!
+//! Function names and return types match the SQLite module one-for-one so
+//! the species test suite can run unmodified against either backend by
+//! swapping which module and which database handle it points at; only the
+//! bind placeholders (`$1`, `$2`, ... instead of `?`) and the pool type
+//! differ. Slugs, `get_species_by_genus_id`, and `SpeciesQuery` have not
+//! grown a Postgres counterpart yet - only the CRUD surface named in
+//! `chunk4-1` has.
+
+use sqlx::{PgPool, Row};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+use crate::types::Species;
+
+/// Insert a new species into the database.
+pub async fn insert_species(pool: &PgPool, species: &Species) -> Result<(), DatabaseError> {
+    sqlx::query(
+        "INSERT INTO species (id, genus_id, specific_epithet, authority, publication_year, conservation_status) \
+         VALUES ($1, $2, $3, $4, $5, $6)",
+    )
+    .bind(species.id.to_string())
+    .bind(species.genus_id.to_string())
+    .bind(&species.specific_epithet)
+    .bind(&species.authority)
+    .bind(species.publication_year)
+    .bind(&species.conservation_status)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Get a species by ID.
+pub async fn get_species_by_id(pool: &PgPool, id: Uuid) -> Result<Option<Species>, DatabaseError> {
+    let row = sqlx::query(
+        "SELECT id, genus_id, specific_epithet, authority, publication_year, conservation_status \
+         FROM species WHERE id = $1",
+    )
+    .bind(id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    row.as_ref().map(row_to_species).transpose()
+}
+
+/// Update a species.
+pub async fn update_species(pool: &PgPool, id: Uuid, species: &Species) -> Result<bool, DatabaseError> {
+    let result = sqlx::query(
+        "UPDATE species SET genus_id = $1, specific_epithet = $2, authority = $3, publication_year = $4, conservation_status = $5 \
+         WHERE id = $6",
+    )
+    .bind(species.genus_id.to_string())
+    .bind(&species.specific_epithet)
+    .bind(&species.authority)
+    .bind(species.publication_year)
+    .bind(&species.conservation_status)
+    .bind(id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a species.
+pub async fn delete_species(pool: &PgPool, id: Uuid) -> Result<bool, DatabaseError> {
+    let result = sqlx::query("DELETE FROM species WHERE id = $1")
+        .bind(id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+fn row_to_species(row: &sqlx::postgres::PgRow) -> Result<Species, DatabaseError> {
+    let id_str: String = row.get("id");
+    let genus_id_str: String = row.get("genus_id");
+
+    Ok(Species::with_id(
+        Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        Uuid::parse_str(&genus_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        row.get("specific_epithet"),
+        row.get("authority"),
+        row.get("publication_year"),
+        row.get("conservation_status"),
+    ))
+}
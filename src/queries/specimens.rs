@@ -1,7 +1,233 @@
-use sqlx::SqlitePool;
+use chrono::NaiveDate;
+use sqlx::{Acquire, Row, Sqlite, SqlitePool};
+use uuid::Uuid;
+
 use crate::error::DatabaseError;
+use crate::types::Specimen;
+
+/// Mean Earth radius in kilometers, used by [`get_specimens_within`]'s
+/// haversine refinement.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// Insert a new specimen using an existing connection or open transaction.
+pub async fn insert_specimen_tx<'a>(
+    conn: impl Acquire<'a, Database = Sqlite>,
+    specimen: &Specimen,
+) -> Result<(), DatabaseError> {
+    specimen.validate()?;
+
+    let mut conn = conn.acquire().await?;
 
-/// Stub implementation for specimens
-pub async fn insert_specimen(_pool: &SqlitePool) -> Result<(), DatabaseError> {
+    sqlx::query(
+        "INSERT INTO specimens (id, species_id, collector, collection_date, latitude, longitude, elevation, notes) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(specimen.id.to_string())
+    .bind(specimen.species_id.to_string())
+    .bind(&specimen.collector)
+    .bind(specimen.collection_date.map(|d| d.to_string()))
+    .bind(specimen.latitude)
+    .bind(specimen.longitude)
+    .bind(specimen.elevation)
+    .bind(&specimen.notes)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Insert a new specimen into the database.
+pub async fn insert_specimen(pool: &SqlitePool, specimen: &Specimen) -> Result<(), DatabaseError> {
+    let mut tx = pool.begin().await?;
+    insert_specimen_tx(&mut tx, specimen).await?;
+    tx.commit().await?;
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Get a specimen by ID.
+pub async fn get_specimen_by_id(pool: &SqlitePool, id: Uuid) -> Result<Option<Specimen>, DatabaseError> {
+    let row = sqlx::query(
+        "SELECT id, species_id, collector, collection_date, latitude, longitude, elevation, notes \
+         FROM specimens WHERE id = ?",
+    )
+    .bind(id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    row.as_ref().map(row_to_specimen).transpose()
+}
+
+/// Get all specimens collected from a species, oldest collection date first
+/// (specimens with no collection date sort last).
+pub async fn get_specimens_by_species(pool: &SqlitePool, species_id: Uuid) -> Result<Vec<Specimen>, DatabaseError> {
+    let rows = sqlx::query(
+        "SELECT id, species_id, collector, collection_date, latitude, longitude, elevation, notes \
+         FROM specimens WHERE species_id = ? \
+         ORDER BY collection_date IS NULL, collection_date",
+    )
+    .bind(species_id.to_string())
+    .fetch_all(pool)
+    .await?;
+
+    let mut specimens = Vec::with_capacity(rows.len());
+    for row in &rows {
+        specimens.push(row_to_specimen(row)?);
+    }
+
+    Ok(specimens)
+}
+
+/// Update a specimen.
+pub async fn update_specimen(pool: &SqlitePool, id: Uuid, specimen: &Specimen) -> Result<bool, DatabaseError> {
+    specimen.validate()?;
+
+    let result = sqlx::query(
+        "UPDATE specimens SET species_id = ?, collector = ?, collection_date = ?, latitude = ?, longitude = ?, elevation = ?, notes = ? \
+         WHERE id = ?",
+    )
+    .bind(specimen.species_id.to_string())
+    .bind(&specimen.collector)
+    .bind(specimen.collection_date.map(|d| d.to_string()))
+    .bind(specimen.latitude)
+    .bind(specimen.longitude)
+    .bind(specimen.elevation)
+    .bind(&specimen.notes)
+    .bind(id.to_string())
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a specimen.
+pub async fn delete_specimen(pool: &SqlitePool, id: Uuid) -> Result<bool, DatabaseError> {
+    let result = sqlx::query("DELETE FROM specimens WHERE id = ?")
+        .bind(id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// A specimen found by [`get_specimens_within`], tagged with its great-circle
+/// distance from the search point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpecimenDistance {
+    pub specimen: Specimen,
+    pub distance_km: f64,
+}
+
+/// Finds georeferenced specimens within `radius_km` of `(lat, lon)`.
+///
+/// A degree-based bounding box is applied in SQL first to discard most of the
+/// table before rows reach Rust (no index covers `latitude`/`longitude`, so
+/// SQLite still scans every row), then each
+/// remaining candidate is refined with an exact haversine distance check in
+/// Rust (a bounding box is not a circle, so it always over-selects slightly
+/// at the corners). Results are returned nearest-first.
+pub async fn get_specimens_within(
+    pool: &SqlitePool,
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+) -> Result<Vec<SpecimenDistance>, DatabaseError> {
+    // One degree of latitude is ~111 km everywhere; one degree of longitude
+    // shrinks by cos(latitude) moving away from the equator, so widen the
+    // longitude side of the box accordingly. Clamped so a search near the
+    // poles (where cos(lat) approaches 0) doesn't blow the box out past +-180.
+    let lat_delta = radius_km / 111.0;
+    let lon_delta = (radius_km / (111.0 * lat.to_radians().cos().abs().max(0.01))).min(180.0);
+
+    let lat_min = lat - lat_delta;
+    let lat_max = lat + lat_delta;
+    let lon_min = lon - lon_delta;
+    let lon_max = lon + lon_delta;
+
+    // A box near the antimeridian can push lon_min/lon_max outside
+    // [-180, 180]; a plain `BETWEEN` never wraps, so split it into the two
+    // ranges either side of the dateline instead of missing specimens on
+    // the far side (e.g. lon=179 with a wide radius must still match -179).
+    let rows = if lon_min < -180.0 || lon_max > 180.0 {
+        let (first_min, first_max, second_min, second_max) = if lon_max > 180.0 {
+            (lon_min, 180.0, -180.0, lon_max - 360.0)
+        } else {
+            (-180.0, lon_max, lon_min + 360.0, 180.0)
+        };
+
+        sqlx::query(
+            "SELECT id, species_id, collector, collection_date, latitude, longitude, elevation, notes \
+             FROM specimens \
+             WHERE latitude IS NOT NULL AND longitude IS NOT NULL \
+             AND latitude BETWEEN ? AND ? \
+             AND (longitude BETWEEN ? AND ? OR longitude BETWEEN ? AND ?)",
+        )
+        .bind(lat_min)
+        .bind(lat_max)
+        .bind(first_min)
+        .bind(first_max)
+        .bind(second_min)
+        .bind(second_max)
+        .fetch_all(pool)
+        .await?
+    } else {
+        sqlx::query(
+            "SELECT id, species_id, collector, collection_date, latitude, longitude, elevation, notes \
+             FROM specimens \
+             WHERE latitude IS NOT NULL AND longitude IS NOT NULL \
+             AND latitude BETWEEN ? AND ? \
+             AND longitude BETWEEN ? AND ?",
+        )
+        .bind(lat_min)
+        .bind(lat_max)
+        .bind(lon_min)
+        .bind(lon_max)
+        .fetch_all(pool)
+        .await?
+    };
+
+    let mut found = Vec::new();
+    for row in &rows {
+        let specimen = row_to_specimen(row)?;
+        let distance_km = haversine_km(lat, lon, specimen.latitude.unwrap(), specimen.longitude.unwrap());
+        if distance_km <= radius_km {
+            found.push(SpecimenDistance { specimen, distance_km });
+        }
+    }
+
+    found.sort_by(|a, b| a.distance_km.total_cmp(&b.distance_km));
+    Ok(found)
+}
+
+/// Great-circle distance between two lat/lon points, in kilometers.
+fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lon1, lat2, lon2) = (lat1.to_radians(), lon1.to_radians(), lat2.to_radians(), lon2.to_radians());
+
+    let dlat = lat2 - lat1;
+    let dlon = lon2 - lon1;
+
+    let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    let c = 2.0 * a.sqrt().asin();
+
+    EARTH_RADIUS_KM * c
+}
+
+fn row_to_specimen(row: &sqlx::sqlite::SqliteRow) -> Result<Specimen, DatabaseError> {
+    let id_str: String = row.get("id");
+    let species_id_str: String = row.get("species_id");
+    let collection_date_str: Option<String> = row.get("collection_date");
+
+    let collection_date = collection_date_str
+        .map(|s| NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(|e| DatabaseError::validation(e.to_string())))
+        .transpose()?;
+
+    Ok(Specimen::with_id(
+        Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        Uuid::parse_str(&species_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+        row.get("collector"),
+        collection_date,
+        row.get("latitude"),
+        row.get("longitude"),
+        row.get("elevation"),
+        row.get("notes"),
+    ))
+}
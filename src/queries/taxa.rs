@@ -0,0 +1,148 @@
+//! Queries over the self-referencing `taxa` table.
+//!
+//! `taxa` lives alongside `families`/`genera`/`species` rather than
+//! replacing them (see [`crate::types::TaxonRank`]); it gives ranks with no
+//! dedicated table - subfamily, tribe, subgenus, subspecies, variety,
+//! cultivar - somewhere to attach, with [`get_subtree`] and [`get_lineage`]
+//! walking the whole tree in one recursive-CTE round trip regardless of how
+//! deep a particular lineage goes.
+
+use sqlx::{Acquire, Row, Sqlite, SqlitePool};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+use crate::types::{Taxon, TaxonRank, taxon::MAX_TAXON_DEPTH};
+
+/// One row of a [`get_subtree`]/[`get_lineage`] walk: a [`Taxon`] plus how
+/// many edges it sits from the starting node (`0` for the starting node
+/// itself).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxonNode {
+    pub taxon: Taxon,
+    pub depth: i64,
+}
+
+fn row_to_node(row: &sqlx::sqlite::SqliteRow) -> Result<TaxonNode, DatabaseError> {
+    let id_str: String = row.get("id");
+    let parent_id_str: Option<String> = row.get("parent_id");
+    let rank_str: String = row.get("rank");
+    let depth: i64 = row.get("depth");
+
+    let parent_id = parent_id_str
+        .map(|s| Uuid::parse_str(&s).map_err(|e| DatabaseError::validation(e.to_string())))
+        .transpose()?;
+
+    Ok(TaxonNode {
+        taxon: Taxon::with_id(
+            Uuid::parse_str(&id_str).map_err(|e| DatabaseError::validation(e.to_string()))?,
+            parent_id,
+            TaxonRank::from_str(&rank_str)
+                .ok_or_else(|| DatabaseError::validation(format!("unknown taxon rank: {}", rank_str)))?,
+            row.get("name"),
+            row.get("authority"),
+        ),
+        depth,
+    })
+}
+
+/// Rejects a recursive-CTE result that went past [`MAX_TAXON_DEPTH`], which
+/// only happens if `taxa.parent_id` contains a cycle or a chain deeper than
+/// the limit (the CTE otherwise terminates naturally once it runs out of
+/// descendants/ancestors; a node at exactly `MAX_TAXON_DEPTH` is accepted).
+/// The CTEs in [`get_subtree`]/[`get_lineage`] are bound
+/// to one row past `MAX_TAXON_DEPTH` specifically so this guard has a row at
+/// the boundary to catch - if they stopped exactly at `MAX_TAXON_DEPTH`
+/// instead, a cycle would look identical to a tree that happens to bottom
+/// out right at the limit, and would be silently truncated rather than
+/// rejected.
+fn guard_depth(nodes: &[TaxonNode]) -> Result<(), DatabaseError> {
+    if nodes.iter().any(|node| node.depth > MAX_TAXON_DEPTH) {
+        return Err(DatabaseError::constraint(format!(
+            "taxa traversal exceeded the maximum depth of {}; the parent_id chain likely contains a cycle",
+            MAX_TAXON_DEPTH
+        )));
+    }
+    Ok(())
+}
+
+/// Insert a new taxon using an existing connection or open transaction.
+pub async fn insert_taxon_tx<'a>(conn: impl Acquire<'a, Database = Sqlite>, taxon: &Taxon) -> Result<(), DatabaseError> {
+    taxon.validate()?;
+
+    let mut conn = conn.acquire().await?;
+
+    sqlx::query("INSERT INTO taxa (id, parent_id, rank, name, authority) VALUES (?, ?, ?, ?, ?)")
+        .bind(taxon.id.to_string())
+        .bind(taxon.parent_id.map(|id| id.to_string()))
+        .bind(taxon.rank.as_str())
+        .bind(&taxon.name)
+        .bind(&taxon.authority)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Insert a new taxon into the database.
+pub async fn insert_taxon(pool: &SqlitePool, taxon: &Taxon) -> Result<(), DatabaseError> {
+    let mut tx = pool.begin().await?;
+    insert_taxon_tx(&mut tx, taxon).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Fetches `id` and every descendant beneath it in a single recursive-CTE
+/// round trip, each tagged with its `depth` below `id` (`0` for `id` itself).
+/// Returns an empty `Vec` if `id` does not exist.
+pub async fn get_subtree(pool: &SqlitePool, id: Uuid) -> Result<Vec<TaxonNode>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        WITH RECURSIVE subtree(id, parent_id, rank, name, authority, depth) AS (
+            SELECT id, parent_id, rank, name, authority, 0
+            FROM taxa WHERE id = ?
+            UNION ALL
+            SELECT t.id, t.parent_id, t.rank, t.name, t.authority, s.depth + 1
+            FROM taxa t
+            JOIN subtree s ON t.parent_id = s.id
+            WHERE s.depth < ?
+        )
+        SELECT * FROM subtree
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(MAX_TAXON_DEPTH + 1)
+    .fetch_all(pool)
+    .await?;
+
+    let nodes = rows.iter().map(row_to_node).collect::<Result<Vec<_>, _>>()?;
+    guard_depth(&nodes)?;
+    Ok(nodes)
+}
+
+/// Fetches `id` and every ancestor up to the root in a single recursive-CTE
+/// round trip, each tagged with its `depth` above `id` (`0` for `id` itself).
+/// Returns an empty `Vec` if `id` does not exist.
+pub async fn get_lineage(pool: &SqlitePool, id: Uuid) -> Result<Vec<TaxonNode>, DatabaseError> {
+    let rows = sqlx::query(
+        r#"
+        WITH RECURSIVE lineage(id, parent_id, rank, name, authority, depth) AS (
+            SELECT id, parent_id, rank, name, authority, 0
+            FROM taxa WHERE id = ?
+            UNION ALL
+            SELECT t.id, t.parent_id, t.rank, t.name, t.authority, s.depth + 1
+            FROM taxa t
+            JOIN lineage s ON t.id = s.parent_id
+            WHERE s.depth < ?
+        )
+        SELECT * FROM lineage
+        "#,
+    )
+    .bind(id.to_string())
+    .bind(MAX_TAXON_DEPTH + 1)
+    .fetch_all(pool)
+    .await?;
+
+    let nodes = rows.iter().map(row_to_node).collect::<Result<Vec<_>, _>>()?;
+    guard_depth(&nodes)?;
+    Ok(nodes)
+}
@@ -0,0 +1,35 @@
+//! URL-friendly slug generation for taxon names.
+
+/// How many times an insert function retries its whole slug-then-insert
+/// sequence after a unique-slug race (two concurrent inserts both pick the
+/// same free slug before either commits) before giving up and surfacing the
+/// `ConstraintViolation`.
+pub const MAX_SLUG_INSERT_ATTEMPTS: usize = 5;
+
+/// Converts `input` into a lowercase, hyphen-separated slug: non-alphanumeric
+/// runs collapse to a single `-`, and leading/trailing hyphens are trimmed
+/// (e.g. `"Triticum aestivum"` -> `"triticum-aestivum"`).
+///
+/// This only derives the base slug from a name; callers that need a slug
+/// unique within a table still have to disambiguate collisions themselves
+/// (see `queries::family`/`genus`/`species`'s insert functions).
+pub fn slugify(input: &str) -> String {
+    let mut slug = String::with_capacity(input.len());
+    let mut last_was_hyphen = true; // suppress a leading hyphen
+
+    for ch in input.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+            last_was_hyphen = false;
+        } else if !last_was_hyphen {
+            slug.push('-');
+            last_was_hyphen = true;
+        }
+    }
+
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+
+    slug
+}
@@ -0,0 +1,279 @@
+//! Incremental ingestion of reference taxonomy from a remote source.
+//!
+//! Each ingested record is tracked in `synced_taxa` by its remote id and a
+//! monotonically increasing version/last-modified marker, so a re-sync only
+//! has to ask the remote source for records changed since the last run's
+//! high-water mark (persisted in `sync_metadata`) instead of re-downloading
+//! the whole dataset every time.
+
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+use crate::queries::{family, genus, species};
+use crate::types::{Family, Genus, Species};
+
+/// The taxonomic rank of a record reported by a remote source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteTaxonRank {
+    Family,
+    Genus,
+    Species,
+}
+
+impl RemoteTaxonRank {
+    fn as_str(&self) -> &'static str {
+        match self {
+            RemoteTaxonRank::Family => "family",
+            RemoteTaxonRank::Genus => "genus",
+            RemoteTaxonRank::Species => "species",
+        }
+    }
+
+    /// Position in the family -> genus -> species hierarchy, used to order
+    /// a batch so a record's parent is always upserted first regardless of
+    /// what order the remote source reported the batch in.
+    fn ingest_order(&self) -> u8 {
+        match self {
+            RemoteTaxonRank::Family => 0,
+            RemoteTaxonRank::Genus => 1,
+            RemoteTaxonRank::Species => 2,
+        }
+    }
+}
+
+/// One record as reported by a remote taxonomy source, flattened across
+/// ranks so a single sync pass can ingest families, genera, and species.
+#[derive(Debug, Clone)]
+pub struct RemoteTaxonRecord {
+    pub remote_id: String,
+    pub version: i64,
+    pub deleted: bool,
+    pub rank: RemoteTaxonRank,
+    /// The remote id of the parent taxon (family for a genus, genus for a species).
+    pub parent_remote_id: Option<String>,
+    pub name: String,
+    pub authority: String,
+    pub publication_year: Option<i32>,
+}
+
+/// A pluggable remote taxonomy source. Implementations talk to whatever
+/// transport backs the curated dataset (HTTP API, flat file, etc.).
+#[async_trait::async_trait]
+pub trait RemoteTaxonomySource: Send + Sync {
+    /// Returns every record with a version greater than `high_water_mark`.
+    async fn changes_since(&self, high_water_mark: i64) -> Result<Vec<RemoteTaxonRecord>, DatabaseError>;
+}
+
+/// Counts of rows touched by one [`BotanicalSync::ingest`] run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IngestSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub deleted: usize,
+    pub high_water_mark: i64,
+}
+
+/// Drives incremental ingestion of a remote taxonomy source into the local database.
+pub struct BotanicalSync<S: RemoteTaxonomySource> {
+    source: S,
+}
+
+impl<S: RemoteTaxonomySource> BotanicalSync<S> {
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Ingests all records changed since the last run, upserting or deleting
+    /// the corresponding local rows, and returns the run's summary.
+    ///
+    /// `changes_since` makes no promise about ordering, but a genus/species
+    /// upsert requires its parent to already be in `synced_taxa`, so upserts
+    /// in the batch are sorted family-then-genus-then-species; deletions are
+    /// sorted the other way round (species-then-genus-then-family) so a
+    /// child row is never left dangling on a parent that was just deleted.
+    /// The sort is stable, so records of the same rank and disposition keep
+    /// the remote source's relative order.
+    pub async fn ingest(&self, pool: &SqlitePool) -> Result<IngestSummary, DatabaseError> {
+        let high_water_mark = read_high_water_mark(pool).await?;
+        let mut records = self.source.changes_since(high_water_mark).await?;
+        records.sort_by_key(|record| {
+            let order = record.rank.ingest_order();
+            if record.deleted {
+                2 - order
+            } else {
+                order
+            }
+        });
+
+        let mut summary = IngestSummary {
+            high_water_mark,
+            ..Default::default()
+        };
+
+        for record in &records {
+            summary.high_water_mark = summary.high_water_mark.max(record.version);
+
+            if record.deleted {
+                if delete_synced(pool, &record.remote_id).await? {
+                    summary.deleted += 1;
+                }
+                continue;
+            }
+
+            if upsert_synced(pool, record).await? {
+                summary.updated += 1;
+            } else {
+                summary.inserted += 1;
+            }
+        }
+
+        write_high_water_mark(pool, summary.high_water_mark).await?;
+        Ok(summary)
+    }
+}
+
+async fn read_high_water_mark(pool: &SqlitePool) -> Result<i64, DatabaseError> {
+    let row = sqlx::query("SELECT high_water_mark FROM sync_metadata WHERE source = 'default'")
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| r.get::<i64, _>("high_water_mark")).unwrap_or(0))
+}
+
+async fn write_high_water_mark(pool: &SqlitePool, high_water_mark: i64) -> Result<(), DatabaseError> {
+    sqlx::query(
+        "INSERT INTO sync_metadata (source, high_water_mark, updated_at) VALUES ('default', ?, ?) \
+         ON CONFLICT(source) DO UPDATE SET high_water_mark = excluded.high_water_mark, updated_at = excluded.updated_at"
+    )
+    .bind(high_water_mark)
+    .bind(Utc::now().to_rfc3339())
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+async fn local_id_for_remote(pool: &SqlitePool, remote_id: &str) -> Result<Option<Uuid>, DatabaseError> {
+    let row = sqlx::query("SELECT local_id FROM synced_taxa WHERE remote_id = ?")
+        .bind(remote_id)
+        .fetch_optional(pool)
+        .await?;
+
+    row.map(|r| {
+        let local_id: String = r.get("local_id");
+        Uuid::parse_str(&local_id).map_err(|e| DatabaseError::validation(e.to_string()))
+    })
+    .transpose()
+}
+
+async fn record_synced(pool: &SqlitePool, record: &RemoteTaxonRecord, local_id: Uuid) -> Result<(), DatabaseError> {
+    sqlx::query(
+        "INSERT INTO synced_taxa (remote_id, rank, local_id, version) VALUES (?, ?, ?, ?) \
+         ON CONFLICT(remote_id) DO UPDATE SET local_id = excluded.local_id, version = excluded.version"
+    )
+    .bind(&record.remote_id)
+    .bind(record.rank.as_str())
+    .bind(local_id.to_string())
+    .bind(record.version)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Upserts a single remote record into its corresponding local table,
+/// returning `true` if an existing row was updated and `false` if inserted.
+async fn upsert_synced(pool: &SqlitePool, record: &RemoteTaxonRecord) -> Result<bool, DatabaseError> {
+    let existing = local_id_for_remote(pool, &record.remote_id).await?;
+
+    match record.rank {
+        RemoteTaxonRank::Family => {
+            let local_id = existing.unwrap_or_else(Uuid::new_v4);
+            let row = Family::with_id(local_id, record.name.clone(), record.authority.clone());
+            if existing.is_some() {
+                family::update_family(pool, local_id, &row).await?;
+            } else {
+                family::insert_family(pool, &row).await?;
+            }
+            record_synced(pool, record, local_id).await?;
+            Ok(existing.is_some())
+        }
+        RemoteTaxonRank::Genus => {
+            let parent_remote_id = record
+                .parent_remote_id
+                .as_deref()
+                .ok_or_else(|| DatabaseError::validation("genus record is missing a parent family remote id"))?;
+            let family_id = local_id_for_remote(pool, parent_remote_id)
+                .await?
+                .ok_or_else(|| DatabaseError::validation("parent family has not been synced yet"))?;
+
+            let local_id = existing.unwrap_or_else(Uuid::new_v4);
+            let row = Genus::with_id(local_id, family_id, record.name.clone(), record.authority.clone());
+            if existing.is_some() {
+                genus::update_genus(pool, local_id, &row).await?;
+            } else {
+                genus::insert_genus(pool, &row).await?;
+            }
+            record_synced(pool, record, local_id).await?;
+            Ok(existing.is_some())
+        }
+        RemoteTaxonRank::Species => {
+            let parent_remote_id = record
+                .parent_remote_id
+                .as_deref()
+                .ok_or_else(|| DatabaseError::validation("species record is missing a parent genus remote id"))?;
+            let genus_id = local_id_for_remote(pool, parent_remote_id)
+                .await?
+                .ok_or_else(|| DatabaseError::validation("parent genus has not been synced yet"))?;
+
+            let local_id = existing.unwrap_or_else(Uuid::new_v4);
+            let row = Species::with_id(
+                local_id,
+                genus_id,
+                record.name.clone(),
+                record.authority.clone(),
+                record.publication_year,
+                None,
+            );
+            if existing.is_some() {
+                species::update_species(pool, local_id, &row).await?;
+            } else {
+                species::insert_species(pool, &row).await?;
+            }
+            record_synced(pool, record, local_id).await?;
+            Ok(existing.is_some())
+        }
+    }
+}
+
+/// Deletes the local row the remote source has dropped, if it was ever synced.
+async fn delete_synced(pool: &SqlitePool, remote_id: &str) -> Result<bool, DatabaseError> {
+    let row = sqlx::query("SELECT rank, local_id FROM synced_taxa WHERE remote_id = ?")
+        .bind(remote_id)
+        .fetch_optional(pool)
+        .await?;
+
+    let Some(row) = row else {
+        return Ok(false);
+    };
+
+    let rank: String = row.get("rank");
+    let local_id_str: String = row.get("local_id");
+    let local_id = Uuid::parse_str(&local_id_str).map_err(|e| DatabaseError::validation(e.to_string()))?;
+
+    let deleted = match rank.as_str() {
+        "family" => family::delete_family(pool, local_id).await?,
+        "genus" => genus::delete_genus(pool, local_id).await?,
+        "species" => species::delete_species(pool, local_id).await?,
+        other => return Err(DatabaseError::validation(format!("unknown synced taxon rank: {}", other))),
+    };
+
+    sqlx::query("DELETE FROM synced_taxa WHERE remote_id = ?")
+        .bind(remote_id)
+        .execute(pool)
+        .await?;
+
+    Ok(deleted)
+}
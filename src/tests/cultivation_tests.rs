@@ -0,0 +1,277 @@
+//! Cultivation record and environment persistence tests
+//!
+//! Tests CRUD operations for `CultivationRecord` and `Environment`.
+
+use super::setup_test_database;
+use crate::queries::cultivation::*;
+use crate::types::{CultivationRecord, Environment, GrowthStage, JournalEntryKind};
+
+#[tokio::test]
+async fn test_insert_and_get_environment() {
+    let db = setup_test_database().await;
+
+    let mut environment = Environment::new();
+    environment.temperature_celsius = Some(21.5);
+    environment.humidity_percent = Some(60.0);
+    environment.ph_level = Some(6.2);
+    environment.light_hours = Some(14.0);
+    environment.co2_ppm = Some(800);
+
+    insert_environment(db.pool(), &environment).await.expect("Failed to insert environment");
+
+    let retrieved = get_environment_by_id(db.pool(), environment.id)
+        .await
+        .expect("Query should succeed")
+        .expect("Environment should exist");
+
+    assert_eq!(retrieved, environment);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_environment() {
+    let db = setup_test_database().await;
+
+    let mut environment = Environment::new();
+    environment.temperature_celsius = Some(18.0);
+    insert_environment(db.pool(), &environment).await.expect("Failed to insert environment");
+
+    environment.temperature_celsius = Some(22.0);
+    let updated = update_environment(db.pool(), environment.id, &environment)
+        .await
+        .expect("Update should succeed");
+    assert!(updated, "Update should report a matched row");
+
+    let retrieved = get_environment_by_id(db.pool(), environment.id)
+        .await
+        .expect("Query should succeed")
+        .expect("Environment should exist");
+    assert_eq!(retrieved.temperature_celsius, Some(22.0));
+
+    let deleted = delete_environment(db.pool(), environment.id).await.expect("Delete should succeed");
+    assert!(deleted, "Delete should report a matched row");
+
+    let retrieved = get_environment_by_id(db.pool(), environment.id).await.expect("Query should succeed");
+    assert!(retrieved.is_none(), "Deleted environment should not be found");
+}
+
+#[tokio::test]
+async fn test_insert_and_get_cultivation_record() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let environment = Environment::new();
+    insert_environment(db.pool(), &environment).await.expect("Failed to insert environment");
+
+    let mut record = CultivationRecord::new(species.id, GrowthStage::Vegetative, "Alice".to_string());
+    record.environment_id = Some(environment.id);
+    record.notes = Some("Healthy growth".to_string());
+    record.photos = vec!["photo1.jpg".to_string(), "photo2.jpg".to_string()];
+
+    insert_cultivation_record(db.pool(), &record).await.expect("Failed to insert cultivation record");
+
+    let retrieved = get_cultivation_record_by_id(db.pool(), record.id)
+        .await
+        .expect("Query should succeed")
+        .expect("Cultivation record should exist");
+
+    assert_eq!(retrieved, record);
+}
+
+#[tokio::test]
+async fn test_get_cultivation_records_by_species_id_ordered() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let earlier = CultivationRecord::new(species.id, GrowthStage::Seedling, "Bob".to_string());
+    insert_cultivation_record(db.pool(), &earlier).await.expect("Failed to insert earlier record");
+
+    let later = CultivationRecord::new(species.id, GrowthStage::Flowering, "Bob".to_string());
+    insert_cultivation_record(db.pool(), &later).await.expect("Failed to insert later record");
+
+    let records = get_cultivation_records_by_species_id(db.pool(), species.id)
+        .await
+        .expect("Query should succeed");
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].id, earlier.id, "records should come back oldest first");
+    assert_eq!(records[1].id, later.id);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_cultivation_record() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let mut record = CultivationRecord::new(species.id, GrowthStage::Seed, "Carol".to_string());
+    insert_cultivation_record(db.pool(), &record).await.expect("Failed to insert cultivation record");
+
+    record.growth_stage = GrowthStage::Germination;
+    let updated = update_cultivation_record(db.pool(), record.id, &record)
+        .await
+        .expect("Update should succeed");
+    assert!(updated, "Update should report a matched row");
+
+    let retrieved = get_cultivation_record_by_id(db.pool(), record.id)
+        .await
+        .expect("Query should succeed")
+        .expect("Cultivation record should exist");
+    assert_eq!(retrieved.growth_stage, GrowthStage::Germination);
+
+    let deleted = delete_cultivation_record(db.pool(), record.id).await.expect("Delete should succeed");
+    assert!(deleted, "Delete should report a matched row");
+
+    let retrieved = get_cultivation_record_by_id(db.pool(), record.id).await.expect("Query should succeed");
+    assert!(retrieved.is_none(), "Deleted cultivation record should not be found");
+}
+
+#[tokio::test]
+async fn test_cultivation_journal_chain_links_entries() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let first = CultivationRecord::new(species.id, GrowthStage::Seed, "Erin".to_string());
+    let first_entry = record_cultivation_event(db.pool(), &first).await.expect("Failed to record first event");
+    assert_eq!(first_entry.sequence, 0);
+    assert_eq!(first_entry.prev_hash, "");
+
+    let second = CultivationRecord::new(species.id, GrowthStage::Germination, "Erin".to_string());
+    let second_entry = record_cultivation_event(db.pool(), &second).await.expect("Failed to record second event");
+    assert_eq!(second_entry.sequence, 1);
+    assert_eq!(second_entry.prev_hash, first_entry.entry_hash);
+
+    let chain = get_cultivation_journal(db.pool(), species.id).await.expect("Failed to fetch journal");
+    assert_eq!(chain.len(), 2);
+
+    let valid = verify_cultivation_journal(db.pool(), species.id).await.expect("Verification should succeed");
+    assert!(valid, "Untampered journal chain should verify");
+}
+
+#[tokio::test]
+async fn test_cultivation_journal_detects_tampering() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let record = CultivationRecord::new(species.id, GrowthStage::Seed, "Frank".to_string());
+    record_cultivation_event(db.pool(), &record).await.expect("Failed to record event");
+
+    sqlx::query("UPDATE cultivation_journal SET entry_hash = 'tampered' WHERE species_id = ?")
+        .bind(species.id.to_string())
+        .execute(db.pool())
+        .await
+        .expect("Failed to tamper with journal row");
+
+    let valid = verify_cultivation_journal(db.pool(), species.id).await.expect("Verification should succeed");
+    assert!(!valid, "Tampered journal chain should fail verification");
+}
+
+#[tokio::test]
+async fn test_record_environment_reading_appends_to_journal() {
+    let db = setup_test_database().await;
+    let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let record = CultivationRecord::new(species.id, GrowthStage::Seed, "Gwen".to_string());
+    let record_entry = record_cultivation_event(db.pool(), &record).await.expect("Failed to record event");
+
+    let mut environment = Environment::new();
+    environment.temperature_celsius = Some(22.0);
+    let env_entry = record_environment_reading(db.pool(), species.id, &environment)
+        .await
+        .expect("Failed to record environment reading");
+
+    assert_eq!(env_entry.kind, JournalEntryKind::EnvironmentReading);
+    assert_eq!(env_entry.entity_id, environment.id);
+    assert_eq!(env_entry.prev_id, Some(record_entry.id));
+
+    let newest_first = iterate_cultivation_journal(db.pool(), species.id).await.expect("iterate should succeed");
+    assert_eq!(newest_first.len(), 2);
+    assert_eq!(newest_first[0].id, env_entry.id, "iterate should walk newest-to-oldest");
+    assert_eq!(newest_first[1].id, record_entry.id);
+
+    let valid = verify_cultivation_journal(db.pool(), species.id).await.expect("Verification should succeed");
+    assert!(valid, "journal mixing record and environment entries should still verify");
+}
+
+#[tokio::test]
+async fn test_concurrent_record_cultivation_events_serialize_without_forking_chain() {
+    use super::fixture::with_test_database;
+    use std::collections::HashSet;
+
+    with_test_database(|db| async move {
+        let (_family, _genus, species) = super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+        let species_id = species.id;
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let db = db.clone();
+            handles.push(tokio::spawn(async move {
+                let record = CultivationRecord::new(species_id, GrowthStage::Vegetative, format!("cultivator-{i}"));
+                record_cultivation_event(db.pool(), &record).await.expect("concurrent append should succeed")
+            }));
+        }
+
+        for handle in handles {
+            handle.await.expect("task should not panic");
+        }
+
+        let chain = get_cultivation_journal(db.pool(), species_id).await.expect("Failed to fetch journal");
+        assert_eq!(chain.len(), 8, "every concurrent append should land exactly one entry");
+
+        let sequences: HashSet<i64> = chain.iter().map(|e| e.sequence).collect();
+        assert_eq!(sequences.len(), 8, "sequences must be unique - a forked chain would duplicate one");
+
+        let valid = verify_cultivation_journal(db.pool(), species_id).await.expect("Verification should succeed");
+        assert!(valid, "concurrently appended chain should still verify despite racing writers");
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_cultivation_record_foreign_key_constraint() {
+    let db = setup_test_database().await;
+    let invalid_record =
+        CultivationRecord::new(uuid::Uuid::new_v4(), GrowthStage::Seed, "Dave".to_string());
+
+    let result = insert_cultivation_record(db.pool(), &invalid_record).await;
+    assert!(
+        matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))),
+        "Insert should fail with a ConstraintViolation due to the missing species_id, got {:?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn test_cultivation_record_builder_requires_all_fields() {
+    let species_id = uuid::Uuid::new_v4();
+
+    let missing_cultivator = CultivationRecord::builder()
+        .species_id(species_id)
+        .growth_stage(GrowthStage::Seedling)
+        .build();
+    assert!(missing_cultivator.is_err(), "Builder should fail without a cultivator");
+
+    let record = CultivationRecord::builder()
+        .species_id(species_id)
+        .growth_stage(GrowthStage::Seedling)
+        .cultivator("Grace")
+        .notes("Looking healthy")
+        .photos(vec!["photo1.jpg".to_string()])
+        .build()
+        .expect("Builder should succeed with all required fields set");
+
+    assert_eq!(record.species_id, species_id);
+    assert_eq!(record.growth_stage, GrowthStage::Seedling);
+    assert_eq!(record.cultivator, "Grace");
+    assert_eq!(record.notes.as_deref(), Some("Looking healthy"));
+    assert_eq!(record.photos, vec!["photo1.jpg".to_string()]);
+}
+
+#[tokio::test]
+async fn test_cultivation_record_builder_rejects_empty_cultivator() {
+    let result = CultivationRecord::builder()
+        .species_id(uuid::Uuid::new_v4())
+        .growth_stage(GrowthStage::Seed)
+        .cultivator("")
+        .build();
+
+    assert!(result.is_err(), "Builder should reject an empty cultivator");
+}
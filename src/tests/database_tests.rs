@@ -2,7 +2,7 @@
 //! 
 //! Tests database initialization, connection pooling, health checks, and migrations.
 
-use crate::database::{BotanicalDatabase, DatabaseConfig};
+use crate::database::{BotanicalDatabase, Database, DatabaseBackend, DatabaseConfig};
 use crate::{create_test_database, initialize_database};
 use sqlx::Row;
 
@@ -43,16 +43,161 @@ async fn test_database_config_creation() {
     assert_eq!(config.url, "sqlite:botanical.db");
     assert_eq!(config.max_connections, 10);
     assert!(config.foreign_keys);
+    assert_eq!(config.backend, DatabaseBackend::Sqlite);
+}
+
+#[tokio::test]
+async fn test_postgres_backend_not_yet_supported() {
+    let config = DatabaseConfig::postgres("postgres://localhost/botanica");
+    assert_eq!(config.backend, DatabaseBackend::Postgres);
+
+    let result = BotanicalDatabase::new(config).await;
+    assert!(result.is_err(), "BotanicalDatabase should reject the Postgres backend; Postgres connects through PgBotanicalDatabase");
+}
+
+#[tokio::test]
+async fn test_mysql_backend_not_yet_supported() {
+    let config = DatabaseConfig::mysql("mysql://localhost/botanica");
+    assert_eq!(config.backend, DatabaseBackend::MySql);
+
+    let result = BotanicalDatabase::new(config).await;
+    assert!(result.is_err(), "MySQL backend should be rejected until a real connector lands");
+}
+
+#[tokio::test]
+async fn test_database_trait_over_sqlite_handle() {
+    async fn drive_through_trait(db: &dyn Database) -> Result<(), crate::error::DatabaseError> {
+        db.migrate().await?;
+        db.health_check().await
+    }
+
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+    assert_eq!(db.backend(), DatabaseBackend::Sqlite);
+
+    let result = drive_through_trait(&db).await;
+    assert!(result.is_ok(), "Driving BotanicalDatabase through the Database trait failed: {:?}", result.err());
 }
 
 #[tokio::test]
 async fn test_database_migration_success() {
     let db = BotanicalDatabase::memory().await.expect("Failed to create database");
-    
+
     let result = db.migrate().await;
     assert!(result.is_ok(), "Migration failed: {:?}", result.err());
 }
 
+#[tokio::test]
+async fn test_migrations_are_idempotent_and_recorded() {
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+
+    db.migrate().await.expect("First migration run should succeed");
+    db.migrate().await.expect("Re-running migrations should be a no-op, not an error");
+
+    let applied_count: i64 = sqlx::query("SELECT COUNT(*) AS count FROM migrations")
+        .fetch_one(db.pool())
+        .await
+        .expect("migrations table should exist after migrating")
+        .get("count");
+
+    assert!(applied_count > 0, "expected at least one migration to be recorded");
+}
+
+#[tokio::test]
+async fn test_schema_version_tracks_migration_progress() {
+    // BotanicalDatabase::new runs the migrator at construction time, so the
+    // schema is already at the latest version by the time this returns.
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+
+    let version = db.schema_version().await.expect("schema_version should succeed");
+    assert!(version > 0, "schema_version should report the latest applied migration after construction");
+
+    db.migrate_to(0).await.expect("Rolling back to version 0 should succeed");
+    assert_eq!(db.schema_version().await.expect("schema_version should succeed"), 0);
+
+    db.migrate().await.expect("Migration should succeed again");
+    assert_eq!(db.schema_version().await.expect("schema_version should succeed"), version);
+}
+
+#[tokio::test]
+async fn test_migrate_to_rolls_back_and_forward() {
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+
+    db.migrate().await.expect("Migration should succeed");
+    let latest = db.schema_version().await.expect("schema_version should succeed");
+
+    db.migrate_to(1).await.expect("Rolling back to version 1 should succeed");
+    assert_eq!(db.schema_version().await.expect("schema_version should succeed"), 1);
+
+    // Rolled-back tables should no longer exist.
+    let genera_table: Option<String> = sqlx::query("SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'genera'")
+        .fetch_optional(db.pool())
+        .await
+        .expect("query should succeed")
+        .map(|row| row.get("name"));
+    assert!(genera_table.is_none(), "genera table should be dropped after rolling back to version 1");
+
+    db.migrate_to(latest).await.expect("Migrating forward again should succeed");
+    assert_eq!(db.schema_version().await.expect("schema_version should succeed"), latest);
+
+    let genera_table: Option<String> = sqlx::query("SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'genera'")
+        .fetch_optional(db.pool())
+        .await
+        .expect("query should succeed")
+        .map(|row| row.get("name"));
+    assert!(genera_table.is_some(), "genera table should exist again after migrating forward");
+}
+
+#[tokio::test]
+async fn test_rollback_steps_back_by_count() {
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+
+    db.migrate().await.expect("Migration should succeed");
+    let latest = db.schema_version().await.expect("schema_version should succeed");
+
+    db.rollback(2).await.expect("Rollback should succeed");
+    assert_eq!(db.schema_version().await.expect("schema_version should succeed"), latest - 2);
+}
+
+#[tokio::test]
+async fn test_duplicate_id_is_classified_as_unique_constraint_violation() {
+    use crate::queries::family::insert_family;
+    use crate::tests::create_test_family;
+
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+    let family = create_test_family();
+
+    insert_family(db.pool(), &family).await.expect("first insert should succeed");
+
+    let result = insert_family(db.pool(), &family).await;
+    match result {
+        Err(crate::DatabaseError::ConstraintViolation(msg)) => {
+            assert!(msg.contains("unique"), "expected a unique-violation message, got: {msg}");
+        }
+        other => panic!("expected a ConstraintViolation from the duplicate primary key, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn test_edited_migration_checksum_is_detected_as_drift() {
+    let db = BotanicalDatabase::memory().await.expect("Failed to create database");
+
+    // Simulate `MIGRATIONS` having been edited in place after version 1 was
+    // already applied, by tampering with its recorded checksum directly.
+    sqlx::query("UPDATE migrations SET checksum = 'tampered' WHERE version = 1")
+        .execute(db.pool())
+        .await
+        .expect("tampering with the recorded checksum should succeed");
+
+    let result = db.migrate().await;
+    assert!(result.is_err(), "a mismatched checksum should be reported instead of silently ignored");
+    match result.unwrap_err() {
+        crate::DatabaseError::MigrationError(msg) => {
+            assert!(msg.contains("version 1") || msg.contains("migration 1"), "error should name the drifted migration: {msg}");
+        }
+        other => panic!("expected a MigrationError, got {other:?}"),
+    }
+}
+
 #[tokio::test]
 async fn test_create_test_database_helper() {
     let result = create_test_database().await;
@@ -162,6 +307,50 @@ async fn test_concurrent_database_access() {
     }
 }
 
+#[tokio::test]
+async fn test_insert_hierarchy_commits_all_rows() {
+    use crate::types::{Family, Genus, Species};
+
+    let db = create_test_database().await.expect("Failed to create test database");
+
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Genus::new(family.id, "Rosa".to_string(), "Linnaeus".to_string());
+    let species = Species::new(genus.id, "canina".to_string(), "Linnaeus".to_string(), Some(1753), None);
+
+    let result = db.insert_hierarchy(&family, &genus, &species).await;
+    assert!(result.is_ok(), "Failed to insert hierarchy: {:?}", result.err());
+
+    let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM species WHERE id = ?")
+        .bind(species.id.to_string())
+        .fetch_one(db.pool())
+        .await
+        .expect("Failed to query species");
+    assert_eq!(row.0, 1, "Species row should have been committed");
+}
+
+#[tokio::test]
+async fn test_insert_hierarchy_rolls_back_on_invalid_genus() {
+    use crate::types::{Family, Genus, Species};
+    use uuid::Uuid;
+
+    let db = create_test_database().await.expect("Failed to create test database");
+
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    // Genus points at a family that was never inserted into this transaction.
+    let genus = Genus::new(Uuid::new_v4(), "Rosa".to_string(), "Linnaeus".to_string());
+    let species = Species::new(genus.id, "canina".to_string(), "Linnaeus".to_string(), Some(1753), None);
+
+    let result = db.insert_hierarchy(&family, &genus, &species).await;
+    assert!(result.is_err(), "Insert should fail on foreign-key violation");
+
+    let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM families WHERE id = ?")
+        .bind(family.id.to_string())
+        .fetch_one(db.pool())
+        .await
+        .expect("Failed to query families");
+    assert_eq!(row.0, 0, "Family insert should have been rolled back with the rest of the transaction");
+}
+
 #[tokio::test]
 async fn test_database_close() {
     let db = create_test_database().await.expect("Failed to create test database");
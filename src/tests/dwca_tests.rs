@@ -0,0 +1,99 @@
+//! Darwin Core Archive import/export tests
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use uuid::Uuid;
+use zip::write::{FileOptions, ZipWriter};
+
+use super::setup_test_database;
+use crate::io::dwca::{export_dwca, import_dwca};
+
+const META_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<archive xmlns="http://rs.tdwg.org/dwc/text/" metadata="eml.xml">
+  <core encoding="UTF-8" fieldsTerminatedBy="," linesTerminatedBy="\n" fieldsEnclosedBy="&quot;" ignoreHeaderLines="1" rowType="http://rs.tdwg.org/dwc/terms/Taxon">
+    <files><location>taxon.txt</location></files>
+    <field index="0" term="http://rs.tdwg.org/dwc/terms/family"/>
+    <field index="1" term="http://rs.tdwg.org/dwc/terms/genus"/>
+    <field index="2" term="http://rs.tdwg.org/dwc/terms/specificEpithet"/>
+    <field index="3" term="http://rs.tdwg.org/dwc/terms/scientificNameAuthorship"/>
+    <field index="4" term="http://rs.tdwg.org/dwc/terms/namePublishedInYear"/>
+    <field index="5" term="http://rs.tdwg.org/dwc/terms/threatStatus"/>
+  </core>
+</archive>
+"#;
+
+/// Writes a minimal DwC-A zip (a `taxon.txt` core file plus `meta.xml`) with
+/// `csv_body` as the CSV rows (including the header line) to a fresh temp
+/// file, returning its path. Mirrors [`super::fixture`]'s temp-file naming.
+fn write_archive(csv_body: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("botanica-dwca-test-{}.zip", Uuid::new_v4()));
+    let file = File::create(&path).expect("failed to create temp archive");
+    let mut writer = ZipWriter::new(file);
+    let options = FileOptions::default();
+
+    writer.start_file("taxon.txt", options).expect("failed to start taxon.txt");
+    writer.write_all(csv_body.as_bytes()).expect("failed to write taxon.txt");
+
+    writer.start_file("meta.xml", options).expect("failed to start meta.xml");
+    writer.write_all(META_XML.as_bytes()).expect("failed to write meta.xml");
+
+    writer.finish().expect("failed to finish archive");
+    path
+}
+
+const HEADER: &str =
+    "dwc:family,dwc:genus,dwc:specificEpithet,dwc:scientificNameAuthorship,dwc:namePublishedInYear,dwc:threatStatus\n";
+
+#[tokio::test]
+async fn test_import_dwca_happy_path() {
+    let db = setup_test_database().await;
+    let csv_body = format!(
+        "{HEADER}Rosaceae,Rosa,rubiginosa,Linnaeus,1753,LC\nRosaceae,Rosa,canina,Linnaeus,1753,LC\n"
+    );
+    let path = write_archive(&csv_body);
+
+    let report = import_dwca(&db, &path).await.expect("import should succeed");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(report.families_imported, 1, "both rows share one family");
+    assert_eq!(report.genera_imported, 1, "both rows share one genus");
+    assert_eq!(report.species_imported, 2);
+    assert!(report.errors.is_empty(), "well-formed rows should not error: {:?}", report.errors);
+}
+
+#[tokio::test]
+async fn test_import_dwca_collects_malformed_row_errors_instead_of_aborting() {
+    let db = setup_test_database().await;
+    // Row 0 is missing dwc:genus (required), row 1 is well-formed.
+    let csv_body = format!("{HEADER}Rosaceae,,rubiginosa,Linnaeus,1753,LC\nRosaceae,Rosa,canina,Linnaeus,1753,LC\n");
+    let path = write_archive(&csv_body);
+
+    let report = import_dwca(&db, &path).await.expect("import should succeed despite a malformed row");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(report.errors.len(), 1, "the malformed row should be recorded, not abort the import");
+    assert_eq!(report.errors[0].row_number, 0);
+    assert_eq!(report.species_imported, 1, "the well-formed row should still import");
+    assert_eq!(report.families_imported, 1);
+    assert_eq!(report.genera_imported, 1);
+}
+
+#[tokio::test]
+async fn test_export_then_import_dwca_round_trips_taxonomy() {
+    let db = setup_test_database().await;
+    super::setup_sample_taxonomy(&db).await.expect("failed to seed taxonomy");
+
+    let export_path = std::env::temp_dir().join(format!("botanica-dwca-export-{}.zip", Uuid::new_v4()));
+    export_dwca(&db, &export_path).await.expect("export should succeed");
+
+    let imported_db = setup_test_database().await;
+    let report = import_dwca(&imported_db, &export_path).await.expect("import should succeed");
+    std::fs::remove_file(&export_path).ok();
+
+    assert_eq!(report.families_imported, 1);
+    assert_eq!(report.genera_imported, 1);
+    assert_eq!(report.species_imported, 1);
+    assert!(report.errors.is_empty());
+}
@@ -184,21 +184,21 @@ async fn test_delete_family_nonexistent() {
 #[tokio::test]
 async fn test_family_data_integrity() {
     let db = setup_test_database().await;
-    
+
     // Test with empty name
     let family_empty_name = Family::new("".to_string(), "Test".to_string());
     let result = insert_family(db.pool(), &family_empty_name).await;
-    assert!(result.is_ok(), "Insert should succeed with empty name");
-    
+    assert!(result.is_err(), "Insert should reject an empty name");
+
     // Test with empty authority
     let family_empty_authority = Family::new("TestFamily".to_string(), "".to_string());
     let result = insert_family(db.pool(), &family_empty_authority).await;
-    assert!(result.is_ok(), "Insert should succeed with empty authority");
-    
+    assert!(result.is_err(), "Insert should reject an empty authority");
+
     // Test with both empty (edge case)
     let family_empty_both = Family::new("".to_string(), "".to_string());
     let result = insert_family(db.pool(), &family_empty_both).await;
-    assert!(result.is_ok(), "Insert should succeed with both fields empty");
+    assert!(result.is_err(), "Insert should reject both fields empty");
 }
 
 #[tokio::test]
@@ -279,6 +279,48 @@ async fn test_family_unicode_names() {
     assert_eq!(retrieved.authority, "Tëst Authör");
 }
 
+#[tokio::test]
+async fn test_get_family_tree_groups_genera_and_species() {
+    use crate::queries::genus::insert_genus;
+    use crate::queries::species::insert_species;
+    use crate::types::{Genus, Species};
+
+    let db = setup_test_database().await;
+
+    let family = create_test_family();
+    insert_family(db.pool(), &family).await.expect("Failed to insert family");
+
+    let genus1 = Genus::new(family.id, "Prunus".to_string(), "Linnaeus".to_string());
+    let genus2 = Genus::new(family.id, "Rosa".to_string(), "Linnaeus".to_string());
+    insert_genus(db.pool(), &genus1).await.expect("Failed to insert genus1");
+    insert_genus(db.pool(), &genus2).await.expect("Failed to insert genus2");
+
+    let species1 = Species::new(genus2.id, "canina".to_string(), "Linnaeus".to_string(), Some(1753), None);
+    let species2 = Species::new(genus2.id, "gallica".to_string(), "Linnaeus".to_string(), Some(1753), None);
+    insert_species(db.pool(), &species1).await.expect("Failed to insert species1");
+    insert_species(db.pool(), &species2).await.expect("Failed to insert species2");
+
+    let tree = get_family_tree(db.pool(), family.id).await
+        .expect("Failed to fetch family tree")
+        .expect("Family tree should exist");
+
+    assert_eq!(tree.family.id, family.id);
+    assert_eq!(tree.genera.len(), 2, "Should group rows into 2 genera");
+    assert_eq!(tree.genera[0].genus.name, "Prunus", "Genera should be ordered by name");
+    assert!(tree.genera[0].species.is_empty(), "Prunus has no species");
+    assert_eq!(tree.genera[1].genus.name, "Rosa");
+    assert_eq!(tree.genera[1].species.len(), 2, "Rosa should have 2 species");
+    assert_eq!(tree.genera[1].species[0].specific_epithet, "canina");
+}
+
+#[tokio::test]
+async fn test_get_family_tree_nonexistent_family() {
+    let db = setup_test_database().await;
+    let result = get_family_tree(db.pool(), Uuid::new_v4()).await
+        .expect("Query should succeed for nonexistent family");
+    assert!(result.is_none(), "No tree should be found for nonexistent family");
+}
+
 #[tokio::test]
 async fn test_family_long_names() {
     let db = setup_test_database().await;
@@ -298,4 +340,77 @@ async fn test_family_long_names() {
     
     assert_eq!(retrieved.name.len(), 1000);
     assert_eq!(retrieved.authority.len(), 1000);
+}
+
+#[tokio::test]
+async fn test_insert_family_generates_slug() {
+    let db = setup_test_database().await;
+
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    insert_family(db.pool(), &family).await.expect("Failed to insert family");
+
+    let retrieved = get_family_by_slug(db.pool(), "rosaceae")
+        .await
+        .expect("Query should succeed")
+        .expect("Family should be findable by its generated slug");
+    assert_eq!(retrieved.id, family.id);
+}
+
+#[tokio::test]
+async fn test_insert_family_disambiguates_duplicate_slug() {
+    let db = setup_test_database().await;
+
+    let first = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    let second = Family::new("Rosaceae".to_string(), "Someone Else".to_string());
+    insert_family(db.pool(), &first).await.expect("Failed to insert first family");
+    insert_family(db.pool(), &second).await.expect("Failed to insert second family");
+
+    let first_by_slug = get_family_by_slug(db.pool(), "rosaceae")
+        .await
+        .expect("Query should succeed")
+        .expect("First family should keep the base slug");
+    assert_eq!(first_by_slug.id, first.id);
+
+    let second_by_slug = get_family_by_slug(db.pool(), "rosaceae-2")
+        .await
+        .expect("Query should succeed")
+        .expect("Second family should get a disambiguated slug");
+    assert_eq!(second_by_slug.id, second.id);
+}
+
+#[tokio::test]
+async fn test_concurrent_insert_family_never_duplicates_slug() {
+    use super::fixture::with_test_database;
+    use sqlx::Row;
+    use std::collections::HashSet;
+
+    with_test_database(|db| async move {
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let db = db.clone();
+            handles.push(tokio::spawn(async move {
+                let family = Family::new("Rosaceae".to_string(), format!("Authority {i}"));
+                insert_family(db.pool(), &family).await.expect("concurrent insert should succeed");
+                family.id
+            }));
+        }
+
+        let mut ids = Vec::new();
+        for handle in handles {
+            ids.push(handle.await.expect("task should not panic"));
+        }
+
+        let mut slugs = HashSet::new();
+        for id in &ids {
+            let row = sqlx::query("SELECT slug FROM families WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(db.pool())
+                .await
+                .expect("inserted family should exist");
+            slugs.insert(row.get::<String, _>("slug"));
+        }
+
+        assert_eq!(slugs.len(), ids.len(), "every concurrently-inserted family must get a distinct slug");
+    })
+    .await;
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+//! Exercises [`super::fixture::with_test_database`]'s own guarantees:
+//! migrations ran, and the temp file (plus sidecars) is gone afterward even
+//! if the test body panicked.
+
+use super::fixture::with_test_database;
+use super::{create_test_family, create_test_genus, create_test_species};
+use crate::queries::{family, genus, species};
+use std::sync::{Arc, Mutex};
+
+#[tokio::test]
+async fn test_fixture_runs_migrations_and_supports_species_crud() {
+    with_test_database(|db| async move {
+        assert!(
+            db.schema_version().await.expect("schema_version should succeed") > 0,
+            "fixture should start fully migrated"
+        );
+
+        let family_row = create_test_family();
+        let genus_row = create_test_genus(family_row.id);
+        let species_row = create_test_species(genus_row.id);
+
+        family::insert_family(db.pool(), &family_row).await.expect("insert_family should succeed");
+        genus::insert_genus(db.pool(), &genus_row).await.expect("insert_genus should succeed");
+        species::insert_species(db.pool(), &species_row).await.expect("insert_species should succeed");
+
+        let fetched = species::get_species_by_id(db.pool(), species_row.id)
+            .await
+            .expect("get_species_by_id should succeed")
+            .expect("species should exist");
+        assert_eq!(fetched, species_row);
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_fixture_cleans_up_temp_file_after_panicking_test() {
+    let captured_path: Arc<Mutex<Option<std::path::PathBuf>>> = Arc::new(Mutex::new(None));
+    let captured_path_for_body = captured_path.clone();
+
+    // `with_test_database` re-raises the test body's panic once cleanup is
+    // done, so catching it here (on its own task, the same trick the
+    // fixture itself uses) lets this test see both effects: the panic
+    // still propagates, and the temp file is already gone by the time it
+    // does.
+    let outer = tokio::spawn(async move {
+        with_test_database(move |db| {
+            let captured_path = captured_path_for_body.clone();
+            async move {
+                *captured_path.lock().unwrap() = db.temp_path().map(|p| p.to_path_buf());
+                panic!("simulated test failure");
+            }
+        })
+        .await;
+    });
+
+    let result = outer.await;
+    assert!(result.is_err(), "the panic should still propagate to the test runner");
+
+    let path = captured_path
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("fixture should have used a temp file, not DATABASE_URL");
+    assert!(!path.exists(), "temp database file should be removed even after the test body panicked");
+}
@@ -205,7 +205,11 @@ async fn test_genus_foreign_key_constraint() {
     );
     
     let result = insert_genus(db.pool(), &invalid_genus).await;
-    assert!(result.is_err(), "Insert should fail due to foreign key constraint");
+    assert!(
+        matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))),
+        "Insert should fail with a ConstraintViolation due to the missing family_id, got {:?}",
+        result
+    );
 }
 
 #[tokio::test]
@@ -213,26 +217,57 @@ async fn test_genus_data_integrity() {
     let db = setup_test_database().await;
     let family = Family::new("TestFamily".to_string(), "Test".to_string());
     insert_family(db.pool(), &family).await.expect("Failed to insert family");
-    
+
     // Test with empty name
     let genus_empty_name = Genus::new(
         family.id,
         "".to_string(),
         "Test".to_string()
     );
-    
+
     let result = insert_genus(db.pool(), &genus_empty_name).await;
-    assert!(result.is_ok(), "Insert should succeed with empty name");
-    
+    assert!(result.is_err(), "Insert should reject an empty name");
+
     // Test with empty authority
     let genus_empty_authority = Genus::new(
         family.id,
         "TestGenus".to_string(),
         "".to_string()
     );
-    
+
     let result = insert_genus(db.pool(), &genus_empty_authority).await;
-    assert!(result.is_ok(), "Insert should succeed with empty authority");
+    assert!(result.is_err(), "Insert should reject an empty authority");
+}
+
+#[tokio::test]
+async fn test_genus_builder_requires_all_fields() {
+    let family_id = Uuid::new_v4();
+
+    let missing_authority = Genus::builder().family_id(family_id).name("Rosa").build();
+    assert!(missing_authority.is_err(), "Builder should fail without an authority");
+
+    let genus = Genus::builder()
+        .family_id(family_id)
+        .name("Rosa")
+        .authority("Linnaeus")
+        .build()
+        .expect("Builder should succeed with all required fields set");
+
+    assert_eq!(genus.family_id, family_id);
+    assert_eq!(genus.name, "Rosa");
+    assert_eq!(genus.authority, "Linnaeus");
+    assert_ne!(genus.id, Uuid::nil());
+}
+
+#[tokio::test]
+async fn test_genus_builder_rejects_empty_name() {
+    let result = Genus::builder()
+        .family_id(Uuid::new_v4())
+        .name("")
+        .authority("Linnaeus")
+        .build();
+
+    assert!(result.is_err(), "Builder should reject an empty name");
 }
 
 #[tokio::test]
@@ -299,4 +334,62 @@ async fn test_multiple_genera_same_name_different_families() {
     assert_eq!(retrieved1.family_id, family1.id);
     assert_eq!(retrieved2.family_id, family2.id);
     assert_eq!(retrieved1.name, retrieved2.name);
+}
+
+#[tokio::test]
+async fn test_insert_genus_generates_slug() {
+    let db = setup_test_database().await;
+
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    insert_family(db.pool(), &family).await.expect("Failed to insert family");
+
+    let genus = Genus::new(family.id, "Rosa".to_string(), "Linnaeus".to_string());
+    insert_genus(db.pool(), &genus).await.expect("Failed to insert genus");
+
+    let retrieved = get_genus_by_slug(db.pool(), "rosa")
+        .await
+        .expect("Query should succeed")
+        .expect("Genus should be findable by its generated slug");
+    assert_eq!(retrieved.id, genus.id);
+}
+
+#[tokio::test]
+async fn test_concurrent_insert_genus_never_duplicates_slug() {
+    use super::fixture::with_test_database;
+    use sqlx::Row;
+    use std::collections::HashSet;
+
+    with_test_database(|db| async move {
+        let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+        insert_family(db.pool(), &family).await.expect("Failed to insert family");
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let db = db.clone();
+            let family_id = family.id;
+            handles.push(tokio::spawn(async move {
+                let genus = Genus::new(family_id, "Rosa".to_string(), format!("Authority {i}"));
+                insert_genus(db.pool(), &genus).await.expect("concurrent insert should succeed");
+                genus.id
+            }));
+        }
+
+        let mut ids = Vec::new();
+        for handle in handles {
+            ids.push(handle.await.expect("task should not panic"));
+        }
+
+        let mut slugs = HashSet::new();
+        for id in &ids {
+            let row = sqlx::query("SELECT slug FROM genera WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(db.pool())
+                .await
+                .expect("inserted genus should exist");
+            slugs.insert(row.get::<String, _>("slug"));
+        }
+
+        assert_eq!(slugs.len(), ids.len(), "every concurrently-inserted genus must get a distinct slug");
+    })
+    .await;
 }
\ No newline at end of file
@@ -0,0 +1,47 @@
+//! Tests for `import::import_taxonomy`'s per-family savepoint isolation.
+
+use crate::import::{import_taxonomy, FamilyImport, GenusImport, TaxonomyBundle};
+use crate::queries::family::get_family_by_id;
+use crate::types::{Family, Genus};
+
+use super::setup_test_database;
+
+#[tokio::test]
+async fn test_import_taxonomy_keeps_prior_families_when_a_later_one_fails() {
+    let db = setup_test_database().await;
+
+    let good_family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    let good_genus = Genus::new(good_family.id, "Rosa".to_string(), "Linnaeus".to_string());
+
+    // A genus pointing at a family id that is never inserted - the FK
+    // violation this triggers should fail only this family's subtree.
+    let bad_family = Family::new("Orchidaceae".to_string(), "Jussieu".to_string());
+    let orphan_genus = Genus::new(uuid::Uuid::new_v4(), "Orchis".to_string(), "Linnaeus".to_string());
+
+    let tree: TaxonomyBundle = vec![
+        FamilyImport {
+            family: good_family.clone(),
+            genera: vec![GenusImport { genus: good_genus.clone(), species: vec![] }],
+        },
+        FamilyImport {
+            family: bad_family.clone(),
+            genera: vec![GenusImport { genus: orphan_genus, species: vec![] }],
+        },
+    ];
+
+    let summary = import_taxonomy(db.pool(), &tree).await.expect("import_taxonomy itself should not error");
+
+    assert_eq!(summary.families_imported, 1, "the failing family's subtree summary is discarded along with its rollback");
+    assert_eq!(summary.genera_imported, 1, "only the good family's genus should have committed");
+    assert_eq!(summary.errors.len(), 1);
+    assert_eq!(summary.errors[0].family_index, 1);
+
+    let persisted_good = get_family_by_id(db.pool(), good_family.id)
+        .await
+        .expect("query should succeed")
+        .expect("the first family should persist despite the second family's failure");
+    assert_eq!(persisted_good.name, good_family.name);
+
+    let persisted_bad = get_family_by_id(db.pool(), bad_family.id).await.expect("query should succeed");
+    assert!(persisted_bad.is_none(), "the failing family's own insert should be rolled back with its savepoint");
+}
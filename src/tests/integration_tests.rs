@@ -58,7 +58,11 @@ async fn test_foreign_key_constraint_genus_to_family() {
     );
     
     let result = insert_genus(db.pool(), &invalid_genus).await;
-    assert!(result.is_err(), "Insert should fail due to foreign key constraint");
+    assert!(
+        matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))),
+        "Insert should fail with a ConstraintViolation due to the missing family_id, got {:?}",
+        result
+    );
 }
 
 #[tokio::test]
@@ -75,7 +79,11 @@ async fn test_foreign_key_constraint_species_to_genus() {
     );
     
     let result = insert_species(db.pool(), &invalid_species).await;
-    assert!(result.is_err(), "Insert should fail due to foreign key constraint");
+    assert!(
+        matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))),
+        "Insert should fail with a ConstraintViolation due to the missing genus_id, got {:?}",
+        result
+    );
 }
 
 #[tokio::test]
@@ -13,6 +13,18 @@ pub mod species_tests;
 pub mod genus_tests;
 pub mod family_tests;
 pub mod integration_tests;
+pub mod cultivation_tests;
+pub mod taxa_tests;
+pub mod specimen_tests;
+pub mod fixture;
+pub mod fixture_tests;
+pub mod search_tests;
+pub mod dwca_tests;
+pub mod sync_tests;
+pub mod import_tests;
+
+#[cfg(feature = "postgres")]
+pub mod species_pg_tests;
 
 /// Helper function to create a test database with sample data
 pub async fn setup_test_database() -> BotanicalDatabase {
@@ -0,0 +1,48 @@
+//! Tests for [`crate::queries::search::search_species`]'s `taxa_fts`-backed
+//! filtering, which replaces `get_species_by_name`'s substring scan.
+
+use super::{setup_sample_taxonomy, setup_test_database};
+use crate::queries::search::{search_species, SpeciesSearchFilters};
+
+#[tokio::test]
+async fn test_search_species_matches_epithet() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to seed taxonomy");
+
+    let hits = search_species(db.pool(), "rubiginosa", 10, &SpeciesSearchFilters::default())
+        .await
+        .expect("search_species should succeed");
+
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].species_id, species.id);
+}
+
+#[tokio::test]
+async fn test_search_species_filters_by_conservation_status() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to seed taxonomy");
+    assert_eq!(species.conservation_status.as_deref(), Some("LC"));
+
+    let matching_filters = SpeciesSearchFilters { conservation_status: Some("LC".to_string()), genus_id: None };
+    let hits = search_species(db.pool(), "rubiginosa", 10, &matching_filters)
+        .await
+        .expect("search_species should succeed");
+    assert_eq!(hits.len(), 1, "status filter matching the seeded species should still return it");
+
+    let non_matching_filters = SpeciesSearchFilters { conservation_status: Some("EX".to_string()), genus_id: None };
+    let hits = search_species(db.pool(), "rubiginosa", 10, &non_matching_filters)
+        .await
+        .expect("search_species should succeed");
+    assert!(hits.is_empty(), "status filter not matching the seeded species should exclude it");
+}
+
+#[tokio::test]
+async fn test_search_species_respects_limit() {
+    let db = setup_test_database().await;
+    setup_sample_taxonomy(&db).await.expect("Failed to seed taxonomy");
+
+    let hits = search_species(db.pool(), "rubiginosa", 0, &SpeciesSearchFilters::default())
+        .await
+        .expect("search_species should succeed");
+    assert!(hits.is_empty(), "a limit of 0 should return no rows");
+}
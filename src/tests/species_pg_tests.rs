@@ -0,0 +1,156 @@
+//! Runs the same species CRUD assertions as `species_tests.rs`, but against
+//! `queries::species_pg` and a real Postgres server.
+//!
+//! Needs `DATABASE_URL` pointed at a scratch Postgres database (these tests
+//! do not spin one up themselves); skipped with a printed note when unset
+//! so `cargo test --features postgres` still passes in environments without
+//! one, e.g. CI jobs that only exercise the SQLite suite.
+
+use uuid::Uuid;
+
+use crate::database::{DatabaseConfig, PgBotanicalDatabase};
+use crate::queries::search::SpeciesSearchFilters;
+use crate::queries::search_pg;
+use crate::queries::species_pg::*;
+use crate::types::{Family, Genus, Species};
+
+async fn connect_or_skip() -> Option<PgBotanicalDatabase> {
+    let Ok(url) = std::env::var("DATABASE_URL") else {
+        eprintln!("skipping species_pg_tests: DATABASE_URL is not set");
+        return None;
+    };
+
+    Some(
+        PgBotanicalDatabase::new(DatabaseConfig::postgres(url))
+            .await
+            .expect("Failed to connect to Postgres"),
+    )
+}
+
+/// Inserts a parent family and genus directly: the Postgres schema enforces
+/// `species.genus_id REFERENCES genera(id)`, and `species_pg` has no
+/// family/genus inserts of its own.
+async fn insert_parent_rows(db: &PgBotanicalDatabase) -> (Family, Genus) {
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Genus::new(family.id, "Rosa".to_string(), "Linnaeus".to_string());
+
+    sqlx::query("INSERT INTO families (id, name, authority) VALUES ($1, $2, $3)")
+        .bind(family.id.to_string())
+        .bind(&family.name)
+        .bind(&family.authority)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert family");
+    sqlx::query("INSERT INTO genera (id, family_id, name, authority) VALUES ($1, $2, $3, $4)")
+        .bind(genus.id.to_string())
+        .bind(genus.family_id.to_string())
+        .bind(&genus.name)
+        .bind(&genus.authority)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert genus");
+
+    (family, genus)
+}
+
+async fn delete_parent_rows(db: &PgBotanicalDatabase, family: &Family, genus: &Genus) {
+    sqlx::query("DELETE FROM genera WHERE id = $1").bind(genus.id.to_string()).execute(db.pool()).await.expect("cleanup genus delete should succeed");
+    sqlx::query("DELETE FROM families WHERE id = $1").bind(family.id.to_string()).execute(db.pool()).await.expect("cleanup family delete should succeed");
+}
+
+#[tokio::test]
+async fn test_insert_and_get_species() {
+    let Some(db) = connect_or_skip().await else { return };
+    let (family, genus) = insert_parent_rows(&db).await;
+
+    let species = Species::new(
+        genus.id,
+        "gallica".to_string(),
+        "Linnaeus".to_string(),
+        Some(1753),
+        Some("LC".to_string()),
+    );
+
+    insert_species(db.pool(), &species).await.expect("Failed to insert species");
+
+    let fetched = get_species_by_id(db.pool(), species.id)
+        .await
+        .expect("get_species_by_id should succeed")
+        .expect("species should exist");
+    assert_eq!(fetched, species);
+
+    delete_species(db.pool(), species.id).await.expect("cleanup delete should succeed");
+    delete_parent_rows(&db, &family, &genus).await;
+}
+
+#[tokio::test]
+async fn test_update_and_delete_species() {
+    let Some(db) = connect_or_skip().await else { return };
+    let (family, genus) = insert_parent_rows(&db).await;
+
+    let mut species = Species::new(genus.id, "canina".to_string(), "Linnaeus".to_string(), None, None);
+    insert_species(db.pool(), &species).await.expect("Failed to insert species");
+
+    species.conservation_status = Some("NT".to_string());
+    let updated = update_species(db.pool(), species.id, &species)
+        .await
+        .expect("update_species should succeed");
+    assert!(updated, "update should affect one row");
+
+    let deleted = delete_species(db.pool(), species.id)
+        .await
+        .expect("delete_species should succeed");
+    assert!(deleted, "delete should affect one row");
+
+    let fetched = get_species_by_id(db.pool(), species.id)
+        .await
+        .expect("get_species_by_id should succeed");
+    assert!(fetched.is_none(), "species should be gone after delete");
+
+    delete_parent_rows(&db, &family, &genus).await;
+}
+
+#[tokio::test]
+async fn test_search_species_matches_epithet_and_filters_by_genus() {
+    let Some(db) = connect_or_skip().await else { return };
+
+    // `species_pg` has no family/genus CRUD of its own yet (only the
+    // surface named in chunk4-1), so the parent rows search_species needs
+    // to join against are inserted directly here.
+    let family = Family::new("Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Genus::new(family.id, "Rosa".to_string(), "Linnaeus".to_string());
+    let species = Species::new(genus.id, "gallica".to_string(), "Linnaeus".to_string(), Some(1753), Some("LC".to_string()));
+
+    sqlx::query("INSERT INTO families (id, name, authority) VALUES ($1, $2, $3)")
+        .bind(family.id.to_string())
+        .bind(&family.name)
+        .bind(&family.authority)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert family");
+    sqlx::query("INSERT INTO genera (id, family_id, name, authority) VALUES ($1, $2, $3, $4)")
+        .bind(genus.id.to_string())
+        .bind(genus.family_id.to_string())
+        .bind(&genus.name)
+        .bind(&genus.authority)
+        .execute(db.pool())
+        .await
+        .expect("Failed to insert genus");
+    insert_species(db.pool(), &species).await.expect("Failed to insert species");
+
+    let hits = search_pg::search_species(db.pool(), "gallica", 10, &SpeciesSearchFilters::default())
+        .await
+        .expect("search_species should succeed");
+    assert_eq!(hits.len(), 1, "search should find the freshly inserted species via its trigger-maintained search_vector");
+    assert_eq!(hits[0].species_id, species.id);
+
+    let non_matching = SpeciesSearchFilters { conservation_status: None, genus_id: Some(Uuid::new_v4()) };
+    let hits = search_pg::search_species(db.pool(), "gallica", 10, &non_matching)
+        .await
+        .expect("search_species should succeed");
+    assert!(hits.is_empty(), "genus filter not matching the seeded species should exclude it");
+
+    delete_species(db.pool(), species.id).await.expect("cleanup delete should succeed");
+    sqlx::query("DELETE FROM genera WHERE id = $1").bind(genus.id.to_string()).execute(db.pool()).await.expect("cleanup genus delete should succeed");
+    sqlx::query("DELETE FROM families WHERE id = $1").bind(family.id.to_string()).execute(db.pool()).await.expect("cleanup family delete should succeed");
+}
@@ -181,7 +181,11 @@ async fn test_species_foreign_key_constraint() {
     );
     
     let result = insert_species(db.pool(), &invalid_species).await;
-    assert!(result.is_err(), "Insert should fail due to foreign key constraint");
+    assert!(
+        matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))),
+        "Insert should fail with a ConstraintViolation due to the missing genus_id, got {:?}",
+        result
+    );
 }
 
 #[tokio::test]
@@ -293,4 +297,127 @@ async fn test_species_methods() {
     species_no_status.set_conservation_status(Some("CR".to_string()));
     assert!(species_no_status.has_conservation_status());
     assert_eq!(species_no_status.get_conservation_status(), Some("CR"));
+}
+
+#[tokio::test]
+async fn test_species_query_combines_predicates() {
+    let db = setup_test_database().await;
+    let (_family, genus, _) = setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let matching = Species::new(
+        genus.id,
+        "gallica".to_string(),
+        "Linnaeus".to_string(),
+        Some(1753),
+        Some("LC".to_string()),
+    );
+    insert_species(db.pool(), &matching).await.expect("Failed to insert matching species");
+
+    let wrong_year = Species::new(
+        genus.id,
+        "galloides".to_string(),
+        "Linnaeus".to_string(),
+        Some(1900),
+        Some("LC".to_string()),
+    );
+    insert_species(db.pool(), &wrong_year).await.expect("Failed to insert out-of-range species");
+
+    let results = SpeciesQuery::new()
+        .epithet_like("gall")
+        .authority_eq("Linnaeus")
+        .published_between(1700, 1800)
+        .conservation_status_in(&["LC", "NT"])
+        .in_genus(genus.id)
+        .order_by(SpeciesOrderBy::Epithet)
+        .execute(db.pool())
+        .await
+        .expect("SpeciesQuery should execute");
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, matching.id);
+}
+
+#[tokio::test]
+async fn test_species_query_in_family_and_limit() {
+    let db = setup_test_database().await;
+    let (family, genus, _) = setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    for epithet in ["alpha", "beta", "gamma"] {
+        let species = Species::new(genus.id, epithet.to_string(), "Tester".to_string(), None, None);
+        insert_species(db.pool(), &species).await.expect("Failed to insert species");
+    }
+
+    let results = SpeciesQuery::new()
+        .in_family(family.id)
+        .order_by(SpeciesOrderBy::Epithet)
+        .limit(2)
+        .execute(db.pool())
+        .await
+        .expect("SpeciesQuery should execute");
+
+    assert_eq!(results.len(), 2);
+
+    let other_family_results = SpeciesQuery::new()
+        .in_family(Uuid::new_v4())
+        .execute(db.pool())
+        .await
+        .expect("SpeciesQuery should execute");
+
+    assert!(other_family_results.is_empty());
+}
+
+#[tokio::test]
+async fn test_insert_species_generates_slug() {
+    let db = setup_test_database().await;
+    let (_family, genus, _species) = setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+    let species = Species::new(genus.id, "canina".to_string(), "Linnaeus".to_string(), Some(1753), None);
+    insert_species(db.pool(), &species).await.expect("Failed to insert species");
+
+    let retrieved = get_species_by_slug(db.pool(), "canina")
+        .await
+        .expect("Query should succeed")
+        .expect("Species should be findable by its generated slug");
+    assert_eq!(retrieved.id, species.id);
+}
+
+#[tokio::test]
+async fn test_concurrent_insert_species_never_duplicates_slug() {
+    use super::fixture::with_test_database;
+    use sqlx::Row;
+    use std::collections::HashSet;
+
+    with_test_database(|db| async move {
+        let (_family, genus, _species) =
+            super::setup_sample_taxonomy(&db).await.expect("Failed to setup taxonomy");
+
+        let mut handles = Vec::new();
+        for i in 0..8 {
+            let db = db.clone();
+            let genus_id = genus.id;
+            handles.push(tokio::spawn(async move {
+                let species = Species::new(genus_id, "canina".to_string(), format!("Authority {i}"), None, None);
+                insert_species(db.pool(), &species).await.expect("concurrent insert should succeed");
+                species.id
+            }));
+        }
+
+        let mut ids = Vec::new();
+        for handle in handles {
+            ids.push(handle.await.expect("task should not panic"));
+        }
+
+        let mut slugs = HashSet::new();
+        for id in &ids {
+            let row = sqlx::query("SELECT slug FROM species WHERE id = ?")
+                .bind(id.to_string())
+                .fetch_one(db.pool())
+                .await
+                .expect("inserted species should exist");
+            slugs.insert(row.get::<String, _>("slug"));
+        }
+
+        assert_eq!(slugs.len(), ids.len(), "every concurrently-inserted species must get a distinct slug");
+    })
+    .await;
 }
\ No newline at end of file
@@ -0,0 +1,138 @@
+//! Specimen CRUD, georeferencing validation, and `get_specimens_within`
+//! bounding-box + haversine search.
+
+use super::{setup_sample_taxonomy, setup_test_database};
+use crate::queries::specimens::*;
+use crate::types::Specimen;
+
+#[tokio::test]
+async fn test_insert_and_get_specimen() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to set up sample taxonomy");
+
+    let specimen = Specimen::builder(species.id)
+        .collector("A. Botanist")
+        .latitude(51.5074)
+        .longitude(-0.1278)
+        .elevation(11.0)
+        .notes("Collected along the riverbank")
+        .build()
+        .expect("builder should succeed with valid coordinates");
+
+    insert_specimen(db.pool(), &specimen).await.expect("Failed to insert specimen");
+
+    let fetched = get_specimen_by_id(db.pool(), specimen.id)
+        .await
+        .expect("get_specimen_by_id should succeed")
+        .expect("specimen should exist");
+
+    assert_eq!(fetched, specimen);
+}
+
+#[tokio::test]
+async fn test_builder_rejects_out_of_range_coordinates() {
+    let species_id = uuid::Uuid::new_v4();
+
+    let result = Specimen::builder(species_id).latitude(120.0).build();
+    assert!(result.is_err(), "latitude outside -90..=90 should be rejected");
+
+    let result = Specimen::builder(species_id).longitude(-200.0).build();
+    assert!(result.is_err(), "longitude outside -180..=180 should be rejected");
+}
+
+#[tokio::test]
+async fn test_get_specimens_by_species() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to set up sample taxonomy");
+
+    let first = Specimen::builder(species.id).collector("A. Botanist").build().unwrap();
+    let second = Specimen::builder(species.id).collector("B. Botanist").build().unwrap();
+
+    insert_specimen(db.pool(), &first).await.expect("Failed to insert first specimen");
+    insert_specimen(db.pool(), &second).await.expect("Failed to insert second specimen");
+
+    let specimens = get_specimens_by_species(db.pool(), species.id)
+        .await
+        .expect("get_specimens_by_species should succeed");
+    assert_eq!(specimens.len(), 2);
+}
+
+#[tokio::test]
+async fn test_update_and_delete_specimen() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to set up sample taxonomy");
+
+    let mut specimen = Specimen::builder(species.id).collector("A. Botanist").build().unwrap();
+    insert_specimen(db.pool(), &specimen).await.expect("Failed to insert specimen");
+
+    specimen.collector = Some("C. Botanist".to_string());
+    let updated = update_specimen(db.pool(), specimen.id, &specimen)
+        .await
+        .expect("update_specimen should succeed");
+    assert!(updated, "update should affect one row");
+
+    let fetched = get_specimen_by_id(db.pool(), specimen.id)
+        .await
+        .expect("get_specimen_by_id should succeed")
+        .expect("specimen should still exist");
+    assert_eq!(fetched.collector.as_deref(), Some("C. Botanist"));
+
+    let deleted = delete_specimen(db.pool(), specimen.id)
+        .await
+        .expect("delete_specimen should succeed");
+    assert!(deleted, "delete should affect one row");
+
+    let fetched = get_specimen_by_id(db.pool(), specimen.id)
+        .await
+        .expect("get_specimen_by_id should succeed");
+    assert!(fetched.is_none(), "specimen should be gone after delete");
+}
+
+#[tokio::test]
+async fn test_get_specimens_within_filters_by_radius_and_orders_by_distance() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to set up sample taxonomy");
+
+    // London
+    let near = Specimen::builder(species.id).latitude(51.5074).longitude(-0.1278).build().unwrap();
+    // Reading, ~60km from London
+    let mid = Specimen::builder(species.id).latitude(51.4543).longitude(-0.9781).build().unwrap();
+    // Tokyo, far away
+    let far = Specimen::builder(species.id).latitude(35.6762).longitude(139.6503).build().unwrap();
+
+    insert_specimen(db.pool(), &near).await.unwrap();
+    insert_specimen(db.pool(), &mid).await.unwrap();
+    insert_specimen(db.pool(), &far).await.unwrap();
+
+    let results = get_specimens_within(db.pool(), 51.5074, -0.1278, 100.0)
+        .await
+        .expect("get_specimens_within should succeed");
+
+    assert_eq!(results.len(), 2, "only the London and Reading specimens are within 100km");
+    assert_eq!(results[0].specimen.id, near.id, "closest specimen should sort first");
+    assert!(results[0].distance_km < results[1].distance_km);
+}
+
+#[tokio::test]
+async fn test_get_specimens_within_wraps_around_the_antimeridian() {
+    let db = setup_test_database().await;
+    let (_, _, species) = setup_sample_taxonomy(&db).await.expect("Failed to set up sample taxonomy");
+
+    // Fiji, just west of the antimeridian.
+    let west_of_dateline = Specimen::builder(species.id).latitude(-17.7134).longitude(179.5).build().unwrap();
+    // Just east of the antimeridian, ~60km from the point above across the date line.
+    let east_of_dateline = Specimen::builder(species.id).latitude(-17.7134).longitude(-179.9).build().unwrap();
+
+    insert_specimen(db.pool(), &west_of_dateline).await.unwrap();
+    insert_specimen(db.pool(), &east_of_dateline).await.unwrap();
+
+    let results = get_specimens_within(db.pool(), -17.7134, 179.5, 100.0)
+        .await
+        .expect("get_specimens_within should succeed");
+
+    assert_eq!(
+        results.len(),
+        2,
+        "both specimens are within 100km of each other across the date line, not just the one on the same side"
+    );
+}
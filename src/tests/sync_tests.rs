@@ -0,0 +1,77 @@
+//! Incremental remote-taxonomy sync tests
+
+use crate::sync::{BotanicalSync, RemoteTaxonRank, RemoteTaxonRecord, RemoteTaxonomySource};
+use crate::DatabaseError;
+
+use super::setup_test_database;
+
+/// A [`RemoteTaxonomySource`] that always returns the same fixed batch,
+/// regardless of `high_water_mark`, for exercising one `ingest` call.
+struct FixedSource {
+    records: Vec<RemoteTaxonRecord>,
+}
+
+#[async_trait::async_trait]
+impl RemoteTaxonomySource for FixedSource {
+    async fn changes_since(&self, _high_water_mark: i64) -> Result<Vec<RemoteTaxonRecord>, DatabaseError> {
+        Ok(self.records.clone())
+    }
+}
+
+#[tokio::test]
+async fn test_ingest_syncs_genus_before_family_even_when_batch_lists_genus_first() {
+    let db = setup_test_database().await;
+
+    // Genus record comes first in the batch, ahead of the family it depends on.
+    let records = vec![
+        RemoteTaxonRecord {
+            remote_id: "genus-1".to_string(),
+            version: 1,
+            deleted: false,
+            rank: RemoteTaxonRank::Genus,
+            parent_remote_id: Some("family-1".to_string()),
+            name: "Rosa".to_string(),
+            authority: "Linnaeus".to_string(),
+            publication_year: None,
+        },
+        RemoteTaxonRecord {
+            remote_id: "family-1".to_string(),
+            version: 1,
+            deleted: false,
+            rank: RemoteTaxonRank::Family,
+            parent_remote_id: None,
+            name: "Rosaceae".to_string(),
+            authority: "Jussieu".to_string(),
+            publication_year: None,
+        },
+    ];
+
+    let sync = BotanicalSync::new(FixedSource { records });
+    let summary = sync.ingest(db.pool()).await.expect("ingest should succeed despite reversed batch order");
+
+    assert_eq!(summary.inserted, 2);
+    assert_eq!(summary.updated, 0);
+    assert_eq!(summary.deleted, 0);
+    assert_eq!(summary.high_water_mark, 1);
+}
+
+#[tokio::test]
+async fn test_ingest_rejects_genus_whose_family_is_missing_from_batch_and_unsynced() {
+    let db = setup_test_database().await;
+
+    let records = vec![RemoteTaxonRecord {
+        remote_id: "genus-1".to_string(),
+        version: 1,
+        deleted: false,
+        rank: RemoteTaxonRank::Genus,
+        parent_remote_id: Some("family-missing".to_string()),
+        name: "Rosa".to_string(),
+        authority: "Linnaeus".to_string(),
+        publication_year: None,
+    }];
+
+    let sync = BotanicalSync::new(FixedSource { records });
+    let result = sync.ingest(db.pool()).await;
+
+    assert!(result.is_err(), "a genus whose family was never synced should fail, not silently partial-insert");
+}
@@ -0,0 +1,97 @@
+//! Self-referencing `taxa` table tests: subtree/lineage traversal and the
+//! cycle guard.
+
+use super::setup_test_database;
+use crate::queries::taxa::*;
+use crate::types::{Taxon, TaxonRank};
+
+#[tokio::test]
+async fn test_insert_and_get_subtree() {
+    let db = setup_test_database().await;
+
+    let family = Taxon::new(None, TaxonRank::Family, "Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Taxon::new(Some(family.id), TaxonRank::Genus, "Rosa".to_string(), "Linnaeus".to_string());
+    let subgenus = Taxon::new(Some(genus.id), TaxonRank::Subgenus, "Hulthemia".to_string(), "Focke".to_string());
+
+    insert_taxon(db.pool(), &family).await.expect("Failed to insert family taxon");
+    insert_taxon(db.pool(), &genus).await.expect("Failed to insert genus taxon");
+    insert_taxon(db.pool(), &subgenus).await.expect("Failed to insert subgenus taxon");
+
+    let subtree = get_subtree(db.pool(), family.id).await.expect("get_subtree should succeed");
+    assert_eq!(subtree.len(), 3, "subtree should include the root and both descendants");
+
+    let root = subtree.iter().find(|n| n.taxon.id == family.id).expect("root present");
+    assert_eq!(root.depth, 0);
+
+    let leaf = subtree.iter().find(|n| n.taxon.id == subgenus.id).expect("leaf present");
+    assert_eq!(leaf.depth, 2);
+}
+
+#[tokio::test]
+async fn test_get_lineage_walks_to_root() {
+    let db = setup_test_database().await;
+
+    let family = Taxon::new(None, TaxonRank::Family, "Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Taxon::new(Some(family.id), TaxonRank::Genus, "Rosa".to_string(), "Linnaeus".to_string());
+    let species = Taxon::new(Some(genus.id), TaxonRank::Species, "canina".to_string(), "Linnaeus".to_string());
+
+    insert_taxon(db.pool(), &family).await.expect("Failed to insert family taxon");
+    insert_taxon(db.pool(), &genus).await.expect("Failed to insert genus taxon");
+    insert_taxon(db.pool(), &species).await.expect("Failed to insert species taxon");
+
+    let lineage = get_lineage(db.pool(), species.id).await.expect("get_lineage should succeed");
+    assert_eq!(lineage.len(), 3, "lineage should include the leaf and both ancestors");
+
+    let root = lineage.iter().find(|n| n.taxon.id == family.id).expect("root present");
+    assert_eq!(root.depth, 2);
+}
+
+#[tokio::test]
+async fn test_get_subtree_on_missing_id_returns_empty() {
+    let db = setup_test_database().await;
+
+    let subtree = get_subtree(db.pool(), uuid::Uuid::new_v4()).await.expect("get_subtree should succeed");
+    assert!(subtree.is_empty(), "subtree of a nonexistent taxon should be empty");
+}
+
+#[tokio::test]
+async fn test_get_subtree_rejects_cycle() {
+    let db = setup_test_database().await;
+
+    let family = Taxon::new(None, TaxonRank::Family, "Rosaceae".to_string(), "Jussieu".to_string());
+    let genus = Taxon::new(Some(family.id), TaxonRank::Genus, "Rosa".to_string(), "Linnaeus".to_string());
+
+    insert_taxon(db.pool(), &family).await.expect("Failed to insert family taxon");
+    insert_taxon(db.pool(), &genus).await.expect("Failed to insert genus taxon");
+
+    // Close the loop behind the insert API's back - a legitimate insert can
+    // never create a cycle since a taxon's parent must already exist - to
+    // exercise what get_subtree/get_lineage do when one somehow occurs.
+    sqlx::query("UPDATE taxa SET parent_id = ? WHERE id = ?")
+        .bind(genus.id.to_string())
+        .bind(family.id.to_string())
+        .execute(db.pool())
+        .await
+        .expect("Failed to close the parent_id cycle");
+
+    let result = get_subtree(db.pool(), family.id).await;
+    assert!(matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))), "a cyclic parent_id chain should be rejected, not silently truncated");
+
+    let result = get_lineage(db.pool(), family.id).await;
+    assert!(matches!(result, Err(crate::DatabaseError::ConstraintViolation(_))), "a cyclic parent_id chain should be rejected, not silently truncated");
+}
+
+#[tokio::test]
+async fn test_taxon_builder_requires_all_fields() {
+    let result = Taxon::builder().name("Rosa").build();
+    assert!(result.is_err(), "builder should reject a missing rank/authority");
+
+    let taxon = Taxon::builder()
+        .rank(TaxonRank::Genus)
+        .name("Rosa")
+        .authority("Linnaeus")
+        .build()
+        .expect("builder should succeed with all required fields set");
+    assert_eq!(taxon.name, "Rosa");
+    assert!(taxon.parent_id.is_none(), "parent_id should default to None");
+}
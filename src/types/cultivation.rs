@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
 
+use crate::error::DatabaseError;
+
 /// Growth stage enumeration
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum GrowthStage {
@@ -15,6 +17,38 @@ pub enum GrowthStage {
     Curing,
 }
 
+impl GrowthStage {
+    /// Stable text discriminant used to store/query this stage in SQL,
+    /// independent of the enum's `Debug` representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GrowthStage::Seed => "seed",
+            GrowthStage::Germination => "germination",
+            GrowthStage::Seedling => "seedling",
+            GrowthStage::Vegetative => "vegetative",
+            GrowthStage::Flowering => "flowering",
+            GrowthStage::Harvest => "harvest",
+            GrowthStage::Drying => "drying",
+            GrowthStage::Curing => "curing",
+        }
+    }
+
+    /// Parses a stable text discriminant back into a `GrowthStage`.
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "seed" => Some(GrowthStage::Seed),
+            "germination" => Some(GrowthStage::Germination),
+            "seedling" => Some(GrowthStage::Seedling),
+            "vegetative" => Some(GrowthStage::Vegetative),
+            "flowering" => Some(GrowthStage::Flowering),
+            "harvest" => Some(GrowthStage::Harvest),
+            "drying" => Some(GrowthStage::Drying),
+            "curing" => Some(GrowthStage::Curing),
+            _ => None,
+        }
+    }
+}
+
 /// Environmental conditions during cultivation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Environment {
@@ -40,6 +74,58 @@ pub struct CultivationRecord {
     pub cultivator: String,
 }
 
+/// The kind of row a [`CultivationJournalEntry`] records, since the journal
+/// covers both [`CultivationRecord`]s and [`Environment`] readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JournalEntryKind {
+    CultivationRecord,
+    EnvironmentReading,
+}
+
+impl JournalEntryKind {
+    /// Stable text discriminant used to store/query this kind in SQL,
+    /// independent of the enum's `Debug` representation.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JournalEntryKind::CultivationRecord => "cultivation_record",
+            JournalEntryKind::EnvironmentReading => "environment_reading",
+        }
+    }
+
+    /// Parses a stable text discriminant back into a `JournalEntryKind`.
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "cultivation_record" => Some(JournalEntryKind::CultivationRecord),
+            "environment_reading" => Some(JournalEntryKind::EnvironmentReading),
+            _ => None,
+        }
+    }
+}
+
+/// One entry in a species' append-only cultivation journal.
+///
+/// `entity_id` points at the `CultivationRecord` or `Environment` row this
+/// entry records, disambiguated by `kind`. `prev_id` links to the previous
+/// entry in the species' chain (the row a species' `heads` entry points at
+/// for the most recent one), following the append-only-log design this
+/// journal is modeled on; `entry_hash` additionally covers this entry's
+/// fields plus `prev_hash` (the previous entry's `entry_hash`, or empty
+/// string for the first entry in the chain), so recomputing the chain from
+/// the first entry onward detects any row that was edited or deleted out
+/// from under the journal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CultivationJournalEntry {
+    pub id: Uuid,
+    pub species_id: Uuid,
+    pub entity_id: Uuid,
+    pub kind: JournalEntryKind,
+    pub sequence: i64,
+    pub prev_id: Option<Uuid>,
+    pub prev_hash: String,
+    pub entry_hash: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
 impl Environment {
     pub fn new() -> Self {
         Self {
@@ -67,4 +153,106 @@ impl CultivationRecord {
             cultivator,
         }
     }
+
+    /// Starts a [`CultivationRecordBuilder`] so callers can set the
+    /// `environment_id`/`notes`/`photos`/`recorded_at` extras by name instead
+    /// of constructing via `new()` and then mutating fields individually.
+    pub fn builder() -> CultivationRecordBuilder {
+        CultivationRecordBuilder::default()
+    }
+
+    /// Checks that `cultivator` is non-empty, returning a
+    /// [`DatabaseError::ValidationError`] if not.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        if self.cultivator.trim().is_empty() {
+            return Err(DatabaseError::validation("cultivation record requires a cultivator"));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`CultivationRecord`] that requires `species_id`,
+/// `growth_stage`, and `cultivator` to be set before `build()` will succeed.
+#[derive(Debug, Clone, Default)]
+pub struct CultivationRecordBuilder {
+    id: Option<Uuid>,
+    species_id: Option<Uuid>,
+    growth_stage: Option<GrowthStage>,
+    environment_id: Option<Uuid>,
+    notes: Option<String>,
+    photos: Vec<String>,
+    recorded_at: Option<DateTime<Utc>>,
+    cultivator: Option<String>,
+}
+
+impl CultivationRecordBuilder {
+    /// Sets a specific UUID instead of generating one at `build()` time.
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn species_id(mut self, species_id: Uuid) -> Self {
+        self.species_id = Some(species_id);
+        self
+    }
+
+    pub fn growth_stage(mut self, growth_stage: GrowthStage) -> Self {
+        self.growth_stage = Some(growth_stage);
+        self
+    }
+
+    pub fn environment_id(mut self, environment_id: Uuid) -> Self {
+        self.environment_id = Some(environment_id);
+        self
+    }
+
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    pub fn photos(mut self, photos: Vec<String>) -> Self {
+        self.photos = photos;
+        self
+    }
+
+    /// Sets `recorded_at` instead of defaulting to `Utc::now()` at `build()`.
+    pub fn recorded_at(mut self, recorded_at: DateTime<Utc>) -> Self {
+        self.recorded_at = Some(recorded_at);
+        self
+    }
+
+    pub fn cultivator(mut self, cultivator: impl Into<String>) -> Self {
+        self.cultivator = Some(cultivator.into());
+        self
+    }
+
+    /// Builds the `CultivationRecord`, failing if a required field is
+    /// missing or the assembled struct does not pass
+    /// [`CultivationRecord::validate`].
+    pub fn build(self) -> Result<CultivationRecord, DatabaseError> {
+        let species_id = self
+            .species_id
+            .ok_or_else(|| DatabaseError::validation("cultivation record requires a species_id"))?;
+        let growth_stage = self
+            .growth_stage
+            .ok_or_else(|| DatabaseError::validation("cultivation record requires a growth_stage"))?;
+        let cultivator = self
+            .cultivator
+            .ok_or_else(|| DatabaseError::validation("cultivation record requires a cultivator"))?;
+
+        let record = CultivationRecord {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            species_id,
+            growth_stage,
+            environment_id: self.environment_id,
+            notes: self.notes,
+            photos: self.photos,
+            recorded_at: self.recorded_at.unwrap_or_else(Utc::now),
+            cultivator,
+        };
+        record.validate()?;
+        Ok(record)
+    }
 }
\ No newline at end of file
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::DatabaseError;
+
+/// Maximum length accepted for a family `name`/`authority` field.
+const MAX_FIELD_LEN: usize = 1024;
+
 /// Represents a family in the botanical taxonomy system.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Family {
@@ -39,4 +44,22 @@ impl Family {
             authority,
         }
     }
+
+    /// Checks that `name` and `authority` are non-empty and within
+    /// `MAX_FIELD_LEN`, returning a [`DatabaseError::ValidationError`] if not.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        if self.name.trim().is_empty() {
+            return Err(DatabaseError::validation("family name must not be empty"));
+        }
+        if self.authority.trim().is_empty() {
+            return Err(DatabaseError::validation("family authority must not be empty"));
+        }
+        if self.name.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("family name exceeds maximum length"));
+        }
+        if self.authority.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("family authority exceeds maximum length"));
+        }
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::DatabaseError;
+
+/// Maximum length accepted for a genus `name`/`authority` field.
+const MAX_FIELD_LEN: usize = 1024;
+
 /// Represents a genus in the botanical taxonomy system.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Genus {
@@ -46,4 +51,85 @@ impl Genus {
             authority,
         }
     }
+
+    /// Starts a [`GenusBuilder`] so callers can set fields by name instead of
+    /// a positional `family_id`/`name`/`authority` argument list.
+    pub fn builder() -> GenusBuilder {
+        GenusBuilder::default()
+    }
+
+    /// Checks that `name` and `authority` are non-empty and within
+    /// `MAX_FIELD_LEN`, returning a [`DatabaseError::ValidationError`] if not.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        if self.name.trim().is_empty() {
+            return Err(DatabaseError::validation("genus name must not be empty"));
+        }
+        if self.authority.trim().is_empty() {
+            return Err(DatabaseError::validation("genus authority must not be empty"));
+        }
+        if self.name.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("genus name exceeds maximum length"));
+        }
+        if self.authority.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("genus authority exceeds maximum length"));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`Genus`] that requires `family_id`, `name`, and `authority`
+/// to be set before `build()` will succeed, so the three fields can't be
+/// transposed the way they can with the positional `Genus::new` call.
+#[derive(Debug, Clone, Default)]
+pub struct GenusBuilder {
+    id: Option<Uuid>,
+    family_id: Option<Uuid>,
+    name: Option<String>,
+    authority: Option<String>,
+}
+
+impl GenusBuilder {
+    /// Sets a specific UUID instead of generating one at `build()` time.
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn family_id(mut self, family_id: Uuid) -> Self {
+        self.family_id = Some(family_id);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn authority(mut self, authority: impl Into<String>) -> Self {
+        self.authority = Some(authority.into());
+        self
+    }
+
+    /// Builds the `Genus`, failing if a required field is missing or the
+    /// assembled struct does not pass [`Genus::validate`].
+    pub fn build(self) -> Result<Genus, DatabaseError> {
+        let family_id = self
+            .family_id
+            .ok_or_else(|| DatabaseError::validation("genus requires a family_id"))?;
+        let name = self
+            .name
+            .ok_or_else(|| DatabaseError::validation("genus requires a name"))?;
+        let authority = self
+            .authority
+            .ok_or_else(|| DatabaseError::validation("genus requires an authority"))?;
+
+        let genus = Genus {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            family_id,
+            name,
+            authority,
+        };
+        genus.validate()?;
+        Ok(genus)
+    }
 }
\ No newline at end of file
@@ -2,8 +2,12 @@ pub mod species;
 pub mod genus;
 pub mod family;
 pub mod cultivation;
+pub mod taxon;
+pub mod specimen;
 
 pub use species::Species;
 pub use genus::Genus;
 pub use family::Family;
-pub use cultivation::{GrowthStage, Environment, CultivationRecord};
\ No newline at end of file
+pub use cultivation::{GrowthStage, Environment, CultivationRecord, CultivationJournalEntry, JournalEntryKind};
+pub use taxon::{Taxon, TaxonRank};
+pub use specimen::Specimen;
\ No newline at end of file
@@ -0,0 +1,168 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+
+/// Represents a collected specimen of a [`crate::types::Species`], with
+/// structured georeferencing instead of a free-text location.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Specimen {
+    /// Unique identifier for the specimen
+    pub id: Uuid,
+
+    /// The species this specimen was collected from
+    pub species_id: Uuid,
+
+    /// Name of the person or institution that collected the specimen
+    pub collector: Option<String>,
+
+    /// Date the specimen was collected
+    pub collection_date: Option<NaiveDate>,
+
+    /// Collection latitude, in decimal degrees (`-90.0..=90.0`)
+    pub latitude: Option<f64>,
+
+    /// Collection longitude, in decimal degrees (`-180.0..=180.0`)
+    pub longitude: Option<f64>,
+
+    /// Elevation above sea level, in meters
+    pub elevation: Option<f64>,
+
+    /// Free-form collection notes
+    pub notes: Option<String>,
+}
+
+impl Specimen {
+    /// Creates a new Specimen instance with a generated UUID and no
+    /// collection details set.
+    pub fn new(species_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            species_id,
+            collector: None,
+            collection_date: None,
+            latitude: None,
+            longitude: None,
+            elevation: None,
+            notes: None,
+        }
+    }
+
+    /// Creates a new Specimen instance with a specific UUID.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_id(
+        id: Uuid,
+        species_id: Uuid,
+        collector: Option<String>,
+        collection_date: Option<NaiveDate>,
+        latitude: Option<f64>,
+        longitude: Option<f64>,
+        elevation: Option<f64>,
+        notes: Option<String>,
+    ) -> Self {
+        Self {
+            id,
+            species_id,
+            collector,
+            collection_date,
+            latitude,
+            longitude,
+            elevation,
+            notes,
+        }
+    }
+
+    /// Starts a [`SpecimenBuilder`] so callers can set the optional
+    /// collection fields by name instead of a long positional argument list.
+    pub fn builder(species_id: Uuid) -> SpecimenBuilder {
+        SpecimenBuilder { species_id, ..Default::default() }
+    }
+
+    /// Checks that `latitude`/`longitude`, when present, fall within valid
+    /// ranges, returning a [`DatabaseError::ValidationError`] if not.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        if let Some(lat) = self.latitude {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err(DatabaseError::validation("specimen latitude must be between -90 and 90"));
+            }
+        }
+        if let Some(lon) = self.longitude {
+            if !(-180.0..=180.0).contains(&lon) {
+                return Err(DatabaseError::validation("specimen longitude must be between -180 and 180"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`Specimen`]. `species_id` is required at construction (every
+/// specimen must reference a species); every other field is optional, since
+/// unlike `family_id`/`genus_id` on the fixed-rank structs, a newly collected
+/// specimen commonly starts with some fields unset.
+#[derive(Debug, Clone, Default)]
+pub struct SpecimenBuilder {
+    id: Option<Uuid>,
+    species_id: Uuid,
+    collector: Option<String>,
+    collection_date: Option<NaiveDate>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    elevation: Option<f64>,
+    notes: Option<String>,
+}
+
+impl SpecimenBuilder {
+    /// Sets a specific UUID instead of generating one at `build()` time.
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn collector(mut self, collector: impl Into<String>) -> Self {
+        self.collector = Some(collector.into());
+        self
+    }
+
+    pub fn collection_date(mut self, collection_date: NaiveDate) -> Self {
+        self.collection_date = Some(collection_date);
+        self
+    }
+
+    pub fn latitude(mut self, latitude: f64) -> Self {
+        self.latitude = Some(latitude);
+        self
+    }
+
+    pub fn longitude(mut self, longitude: f64) -> Self {
+        self.longitude = Some(longitude);
+        self
+    }
+
+    pub fn elevation(mut self, elevation: f64) -> Self {
+        self.elevation = Some(elevation);
+        self
+    }
+
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = Some(notes.into());
+        self
+    }
+
+    /// Builds the `Specimen`, failing if the assembled struct does not pass
+    /// [`Specimen::validate`].
+    pub fn build(self) -> Result<Specimen, DatabaseError> {
+        let specimen = Specimen {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            species_id: self.species_id,
+            collector: self.collector,
+            collection_date: self.collection_date,
+            latitude: self.latitude,
+            longitude: self.longitude,
+            elevation: self.elevation,
+            notes: self.notes,
+        };
+        specimen.validate()?;
+        Ok(specimen)
+    }
+}
@@ -0,0 +1,189 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::DatabaseError;
+
+/// Maximum length accepted for a taxon `name`/`authority` field.
+const MAX_FIELD_LEN: usize = 1024;
+
+/// Maximum depth [`crate::queries::taxa::get_subtree`]/[`crate::queries::taxa::get_lineage`]
+/// will walk before concluding the `taxa` tree contains a cycle.
+pub const MAX_TAXON_DEPTH: i64 = 64;
+
+/// A taxonomic rank storable in the self-referencing `taxa` table.
+///
+/// `families`/`genera`/`species` remain the dedicated tables and CRUD
+/// surface for those three ranks (FTS, the cultivation journal, and the
+/// existing query layer all key off them); `taxa` exists alongside them so
+/// intermediate and infraspecific ranks with no table of their own
+/// (subfamily, tribe, subgenus, subspecies, variety, cultivar) have
+/// somewhere to live, with [`crate::queries::taxa::get_subtree`] and
+/// [`crate::queries::taxa::get_lineage`] walking across all of them in one
+/// recursive-CTE round trip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaxonRank {
+    Family,
+    Subfamily,
+    Tribe,
+    Genus,
+    Subgenus,
+    Species,
+    Subspecies,
+    Variety,
+    Cultivar,
+}
+
+impl TaxonRank {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaxonRank::Family => "family",
+            TaxonRank::Subfamily => "subfamily",
+            TaxonRank::Tribe => "tribe",
+            TaxonRank::Genus => "genus",
+            TaxonRank::Subgenus => "subgenus",
+            TaxonRank::Species => "species",
+            TaxonRank::Subspecies => "subspecies",
+            TaxonRank::Variety => "variety",
+            TaxonRank::Cultivar => "cultivar",
+        }
+    }
+
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "family" => Some(TaxonRank::Family),
+            "subfamily" => Some(TaxonRank::Subfamily),
+            "tribe" => Some(TaxonRank::Tribe),
+            "genus" => Some(TaxonRank::Genus),
+            "subgenus" => Some(TaxonRank::Subgenus),
+            "species" => Some(TaxonRank::Species),
+            "subspecies" => Some(TaxonRank::Subspecies),
+            "variety" => Some(TaxonRank::Variety),
+            "cultivar" => Some(TaxonRank::Cultivar),
+            _ => None,
+        }
+    }
+}
+
+/// A node in the self-referencing taxonomic tree.
+///
+/// `parent_id` is `None` for a root (typically a family with no parent
+/// recorded in `taxa`); any other rank can nest under any other, so e.g. a
+/// `Cultivar` can parent another `Cultivar` without a schema change.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Taxon {
+    pub id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub rank: TaxonRank,
+    pub name: String,
+    pub authority: String,
+}
+
+impl Taxon {
+    /// Creates a new Taxon instance with a generated UUID.
+    pub fn new(parent_id: Option<Uuid>, rank: TaxonRank, name: String, authority: String) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            parent_id,
+            rank,
+            name,
+            authority,
+        }
+    }
+
+    /// Creates a new Taxon instance with a specific UUID.
+    pub fn with_id(id: Uuid, parent_id: Option<Uuid>, rank: TaxonRank, name: String, authority: String) -> Self {
+        Self {
+            id,
+            parent_id,
+            rank,
+            name,
+            authority,
+        }
+    }
+
+    /// Starts a [`TaxonBuilder`] so callers can set fields by name instead of
+    /// a positional `parent_id`/`rank`/`name`/`authority` argument list.
+    pub fn builder() -> TaxonBuilder {
+        TaxonBuilder::default()
+    }
+
+    /// Checks that `name` and `authority` are non-empty and within
+    /// `MAX_FIELD_LEN`, returning a [`DatabaseError::ValidationError`] if not.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        if self.name.trim().is_empty() {
+            return Err(DatabaseError::validation("taxon name must not be empty"));
+        }
+        if self.authority.trim().is_empty() {
+            return Err(DatabaseError::validation("taxon authority must not be empty"));
+        }
+        if self.name.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("taxon name exceeds maximum length"));
+        }
+        if self.authority.len() > MAX_FIELD_LEN {
+            return Err(DatabaseError::validation("taxon authority exceeds maximum length"));
+        }
+        Ok(())
+    }
+}
+
+/// Builder for [`Taxon`] that requires `rank`, `name`, and `authority` to be
+/// set before `build()` will succeed. `parent_id` defaults to `None` (a root
+/// taxon) since, unlike `family_id`/`genus_id` on the fixed-rank structs, a
+/// missing parent is a valid, common case here.
+#[derive(Debug, Clone, Default)]
+pub struct TaxonBuilder {
+    id: Option<Uuid>,
+    parent_id: Option<Uuid>,
+    rank: Option<TaxonRank>,
+    name: Option<String>,
+    authority: Option<String>,
+}
+
+impl TaxonBuilder {
+    /// Sets a specific UUID instead of generating one at `build()` time.
+    pub fn id(mut self, id: Uuid) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn parent_id(mut self, parent_id: Uuid) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+
+    pub fn rank(mut self, rank: TaxonRank) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn authority(mut self, authority: impl Into<String>) -> Self {
+        self.authority = Some(authority.into());
+        self
+    }
+
+    /// Builds the `Taxon`, failing if `rank`/`name`/`authority` are missing
+    /// or the assembled struct does not pass [`Taxon::validate`].
+    pub fn build(self) -> Result<Taxon, DatabaseError> {
+        let rank = self.rank.ok_or_else(|| DatabaseError::validation("taxon requires a rank"))?;
+        let name = self.name.ok_or_else(|| DatabaseError::validation("taxon requires a name"))?;
+        let authority = self
+            .authority
+            .ok_or_else(|| DatabaseError::validation("taxon requires an authority"))?;
+
+        let taxon = Taxon {
+            id: self.id.unwrap_or_else(Uuid::new_v4),
+            parent_id: self.parent_id,
+            rank,
+            name,
+            authority,
+        };
+        taxon.validate()?;
+        Ok(taxon)
+    }
+}